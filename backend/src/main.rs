@@ -1,13 +1,51 @@
 use axum::{
-    routing::get,
+    extract::State,
+    routing::{get, post},
     Router,
     Json,
 };
+use axum_server::tls_rustls::RustlsConfig;
+use netstat2::{iterate_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo, TcpState};
 use serde::{Serialize};
-use std::net::SocketAddr;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, RwLock};
+use sysinfo::{Pid, System};
+use tokio::sync::Notify;
 use tower_http::cors::{CorsLayer};
 use axum::http::HeaderValue;
 
+/// Per-session detail tracked as the engine establishes/terminates sessions.
+#[derive(Debug, Clone, Serialize)]
+struct SessionInfo {
+    client_id: String,
+    state: String,
+    bitrate_bps: u64,
+}
+
+/// Shared application state read by the HTTP handlers. `upsert_session`/
+/// `remove_session` record sessions as they come and go; no `CoreEngine` is
+/// wired into this binary, so nothing calls them and the session table only
+/// changes when shutdown clears it.
+#[derive(Default)]
+struct AppState {
+    bind_port: u16,
+    sessions: RwLock<HashMap<String, SessionInfo>>,
+    /// Signaled by `/api/shutdown` to drive a graceful stop; SIGINT is
+    /// awaited alongside it in `shutdown_signal`.
+    shutdown: Notify,
+}
+
+impl AppState {
+    fn upsert_session(&self, info: SessionInfo) {
+        self.sessions.write().unwrap().insert(info.client_id.clone(), info);
+    }
+
+    fn remove_session(&self, client_id: &str) {
+        self.sessions.write().unwrap().remove(client_id);
+    }
+}
+
 #[tokio::main]
 async fn main() {
     // Initialize tracing (optional but good for debugging)
@@ -20,23 +58,128 @@ async fn main() {
         .unwrap_or_else(|_| "http://localhost:3000".to_string());
     let cors = CorsLayer::new()
         .allow_origin(allowed_origin.parse::<HeaderValue>().unwrap())
-        .allow_methods([axum::http::Method::GET])
+        .allow_methods([axum::http::Method::GET, axum::http::Method::POST])
         .allow_headers([axum::http::header::CONTENT_TYPE]);
 
+    let bind_host = std::env::var("SKYBRIDGE_BIND_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+    let bind_port: u16 = std::env::var("SKYBRIDGE_BIND_PORT").ok().and_then(|v| v.parse().ok()).unwrap_or(8080);
+
+    let state = Arc::new(AppState {
+        bind_port,
+        sessions: RwLock::new(HashMap::new()),
+        shutdown: Notify::new(),
+    });
+
     // Build our application with a route
     let app = Router::new()
         .route("/", get(root))
         .route("/api/status", get(get_status))
-        .layer(cors);
+        .route("/api/sessions", get(get_sessions))
+        .route("/api/shutdown", post(post_shutdown))
+        .layer(cors)
+        .with_state(state.clone());
+
+    // The heartbeat loop runs on its own abortable task so a shutdown can
+    // stop it cleanly instead of relying on the process being killed.
+    let heartbeat_task = tokio::spawn(heartbeat_loop(state.clone()));
 
-    // Run it
-    // Bind address (default localhost only)
-    let bind_host = std::env::var("SKYBRIDGE_BIND_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
-    let bind_port: u16 = std::env::var("SKYBRIDGE_BIND_PORT").ok().and_then(|v| v.parse().ok()).unwrap_or(8080);
     let addr: SocketAddr = format!("{bind_host}:{bind_port}").parse().unwrap();
-    println!("listening on {}", addr);
-    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    let tls_cert = std::env::var("SKYBRIDGE_TLS_CERT").ok();
+    let tls_key = std::env::var("SKYBRIDGE_TLS_KEY").ok();
+
+    let serve_result = match (tls_cert, tls_key) {
+        (Some(cert_path), Some(key_path)) => {
+            serve_tls(addr, app, &state, &cert_path, &key_path).await
+        }
+        _ if is_loopback(&bind_host) => serve_plaintext(addr, app, &state).await,
+        _ => {
+            eprintln!(
+                "refusing to bind {addr} without TLS: set SKYBRIDGE_TLS_CERT/SKYBRIDGE_TLS_KEY \
+                 or bind to loopback"
+            );
+            std::process::exit(1);
+        }
+    };
+
+    heartbeat_task.abort();
+    // Engine shutdown drives every tracked session to Disconnected; with no
+    // engine wired into this binary yet, clearing the session table mirrors
+    // that effect locally.
+    state.sessions.write().unwrap().clear();
+
+    if let Err(err) = serve_result {
+        eprintln!("server error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn is_loopback(bind_host: &str) -> bool {
+    bind_host
+        .parse::<IpAddr>()
+        .map(|ip| ip.is_loopback())
+        .unwrap_or(false)
+}
+
+async fn shutdown_signal(state: Arc<AppState>) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install SIGINT handler");
+    };
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = state.shutdown.notified() => {}
+    }
+}
+
+async fn serve_plaintext(
+    addr: SocketAddr,
+    app: Router,
+    state: &Arc<AppState>,
+) -> std::io::Result<()> {
+    println!("listening on {addr} (plaintext)");
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(state.clone()))
+        .await
+}
+
+async fn serve_tls(
+    addr: SocketAddr,
+    app: Router,
+    state: &Arc<AppState>,
+    cert_path: &str,
+    key_path: &str,
+) -> std::io::Result<()> {
+    let tls_config = RustlsConfig::from_pem_file(cert_path, key_path)
+        .await
+        .expect("failed to load TLS certificate/key");
+
+    println!("listening on {addr} (tls)");
+    let server = axum_server::bind_rustls(addr, tls_config).serve(app.into_make_service());
+    let handle = axum_server::Handle::new();
+    let shutdown_handle = handle.clone();
+    let shutdown_state = state.clone();
+    tokio::spawn(async move {
+        shutdown_signal(shutdown_state).await;
+        shutdown_handle.graceful_shutdown(Some(std::time::Duration::from_secs(5)));
+    });
+    server.handle(handle).await
+}
+
+/// Placeholder for the engine's periodic heartbeat; replaced by a real
+/// `CoreEngine::send_heartbeat` call once the engine is wired into this
+/// binary. Runs until aborted on shutdown.
+async fn heartbeat_loop(_state: Arc<AppState>) {
+    let mut ticker = tokio::time::interval(std::time::Duration::from_secs(30));
+    loop {
+        ticker.tick().await;
+    }
+}
+
+async fn post_shutdown(State(state): State<Arc<AppState>>) -> &'static str {
+    state.shutdown.notify_one();
+    "shutting down"
 }
 
 async fn root() -> &'static str {
@@ -51,11 +194,51 @@ struct SystemStatus {
     transfer_tasks: u32,
 }
 
-async fn get_status() -> Json<SystemStatus> {
+/// Enumerates established inbound TCP sockets bound to `bind_port`, returning
+/// the connection count and the set of owning PIDs so transfer worker
+/// processes can be counted distinctly from the raw connection count.
+fn scan_sockets(bind_port: u16) -> (u32, Vec<Pid>) {
+    let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+    let proto_flags = ProtocolFlags::TCP;
+
+    let mut active_sessions = 0u32;
+    let mut owning_pids = std::collections::HashSet::new();
+
+    let Ok(sockets) = iterate_sockets_info(af_flags, proto_flags) else {
+        return (0, Vec::new());
+    };
+
+    for info in sockets.flatten() {
+        if let ProtocolSocketInfo::Tcp(tcp) = info.protocol_socket_info {
+            if tcp.local_port != bind_port || tcp.state != TcpState::Established {
+                continue;
+            }
+            active_sessions += 1;
+            owning_pids.extend(info.associated_pids.iter().map(|pid| Pid::from_u32(*pid)));
+        }
+    }
+
+    (active_sessions, owning_pids.into_iter().collect())
+}
+
+async fn get_status(State(state): State<Arc<AppState>>) -> Json<SystemStatus> {
+    let (active_sessions, owning_pids) = scan_sockets(state.bind_port);
+
+    let mut sys = System::new();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&owning_pids), true);
+    let transfer_tasks = owning_pids
+        .iter()
+        .filter(|pid| sys.process(**pid).is_some())
+        .count() as u32;
+
     Json(SystemStatus {
         status: "Running Smoothly".to_string(),
-        online_devices: 0,
-        active_sessions: 0,
-        transfer_tasks: 0,
+        online_devices: state.sessions.read().unwrap().len() as u32,
+        active_sessions,
+        transfer_tasks,
     })
 }
+
+async fn get_sessions(State(state): State<Arc<AppState>>) -> Json<Vec<SessionInfo>> {
+    Json(state.sessions.read().unwrap().values().cloned().collect())
+}
@@ -32,11 +32,40 @@ pub enum CoreError {
     RateLimited { retry_in_ms: u64 },
     #[error("invalid configuration: {reason}")]
     InvalidConfig { reason: String },
+    #[error("path validation failed: no PATH_RESPONSE received for candidate path")]
+    PathValidationFailed,
+    #[error("circuit open, retry in {retry_in_ms} ms")]
+    CircuitOpen { retry_in_ms: u64 },
+    #[error("resumption token rejected: {reason}")]
+    ResumptionRejected { reason: String },
+    #[error("peer identity verification failed: {reason}")]
+    IdentityVerification { reason: String },
+    #[error("key rotation failed: {reason}")]
+    RotationFailed { reason: String },
+    #[error("directional nonce counter exhausted, key rotation required")]
+    CounterExhausted,
+    #[error("reconnect exhausted after {attempts} attempts over {elapsed_ms} ms: {last_error}")]
+    ReconnectExhausted {
+        attempts: u32,
+        elapsed_ms: u64,
+        last_error: Box<CoreError>,
+    },
+    #[error("insufficient shares to reconstruct secret: need {required}, received {received}")]
+    InsufficientShares { required: usize, received: usize },
+    #[error("share verification failed for index {index}")]
+    ShareVerificationFailed { index: u16 },
     #[error("invalid session state: expected {expected}, got {actual:?}")]
     InvalidState {
         expected: String,
         actual: SessionState,
     },
+    #[error("frame out of sequence: expected {expected}, got {actual}")]
+    FrameSequenceMismatch { expected: u64, actual: u64 },
+    #[error(
+        "no protocol version in common: peer requires at least {requested_min}, \
+         this engine supports up to {max_supported}"
+    )]
+    UnsupportedProtocol { requested_min: u16, max_supported: u16 },
 }
 
 pub type CoreResult<T> = Result<T, CoreError>;
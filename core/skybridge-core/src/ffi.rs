@@ -1,14 +1,43 @@
 use crate::crypto::{P256KeyExchange, P256SessionCrypto, SessionCryptoProvider, SessionSecrets};
 use crate::error::{CoreError, CoreResult};
-use crate::session::{AsyncSessionManager, HeartbeatEmitter, SessionConfig, SessionState};
-use crate::stream::{FlowRate, StreamController, StreamMetrics};
+use crate::session::{
+    AsyncSessionManager, EventBroker, HeartbeatEmitter, SessionConfig, SessionEvent, SessionState,
+};
+use crate::stream::{AbrConfig, FlowRate, RttSampler, StreamController, StreamMetrics};
 use crate::CoreEngine;
-use std::collections::VecDeque;
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_void};
 use std::str::from_utf8;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::runtime::Runtime;
 
+/// Signature of a callback registered through
+/// `skybridge_engine_set_event_callback`. `data_ptr`/`data_len` describe the
+/// same payload `skybridge_engine_poll_events` would have returned; the
+/// pointer is only valid for the duration of the call and must not be
+/// retained past it. `user_data` is passed through unchanged from
+/// registration.
+pub type SkybridgeEventCallbackFn = extern "C" fn(
+    kind: SkybridgeEventKind,
+    data_ptr: *const u8,
+    data_len: usize,
+    user_data: *mut c_void,
+);
+
+/// A registered event callback plus the opaque pointer it was registered
+/// with.
+struct EventCallback {
+    callback: SkybridgeEventCallbackFn,
+    user_data: *mut c_void,
+}
+
+// SAFETY: `user_data` is never dereferenced by this crate; it is only ever
+// handed back, unchanged, to the C caller that registered it, which owns
+// its thread-safety.
+unsafe impl Send for EventCallback {}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SkybridgeErrorCode {
@@ -22,6 +51,13 @@ pub enum SkybridgeErrorCode {
     StreamError = 101,
     CryptoError = 102,
     InvalidInput = 200,
+    /// `skybridge_engine_feed` received a frame whose sequence number
+    /// didn't match the one expected next; see `CoreError::FrameSequenceMismatch`.
+    FrameSequenceMismatch = 201,
+    /// `skybridge_engine_connect`'s `min_supported` exceeds
+    /// `skybridge_core::PROTOCOL_VERSION_MAX`, so no protocol version is
+    /// mutually supported; see `CoreError::UnsupportedProtocol`.
+    UnsupportedProtocol = 202,
 }
 
 #[repr(C)]
@@ -42,6 +78,28 @@ pub struct SkybridgeSessionConfig {
     pub heartbeat_interval_ms: u64,
     pub peer_public_key_ptr: *const u8,
     pub peer_public_key_len: usize,
+    /// Backoff tunables for automatic reconnection; a `reconnect_max_delay_ms`
+    /// of `0` leaves `reconnect_strategy` unset, so the engine falls back to
+    /// `ReconnectStrategy::default()`.
+    pub reconnect_base_delay_ms: u64,
+    pub reconnect_max_delay_ms: u64,
+    /// `0` means unlimited retries (`ReconnectStrategy::max_attempts: None`).
+    pub reconnect_max_retries: u32,
+    /// Highest wire-protocol version this side is willing to speak.
+    pub protocol_version: u16,
+    /// Oldest wire-protocol version this side will accept; rejected with
+    /// `SkybridgeErrorCode::UnsupportedProtocol` if it exceeds
+    /// `skybridge_core::PROTOCOL_VERSION_MAX`, since no common version
+    /// would exist to negotiate down to.
+    pub min_supported: u16,
+    /// Tunables for `skybridge_engine_check_liveness_auto`'s RTT-derived
+    /// deadline; an `adaptive_liveness_max_deadline_ms` of `0` leaves
+    /// `adaptive_liveness` unset, so the engine falls back to
+    /// `AdaptiveLivenessConfig::default()`.
+    pub adaptive_liveness_min_deadline_ms: u64,
+    pub adaptive_liveness_max_deadline_ms: u64,
+    /// `0` falls back to `AdaptiveLivenessConfig::default().fallback_multiplier`.
+    pub adaptive_liveness_fallback_multiplier: u32,
 }
 
 #[repr(C)]
@@ -53,6 +111,21 @@ pub enum SkybridgeEventKind {
     HeartbeatAck = 3,
     InputReceived = 4,
     Reconnected = 5,
+    ReconnectExhausted = 6,
+    HeartbeatTimeout = 7,
+    /// Carries the new target as an 8-byte little-endian `u64` payload.
+    BitrateChanged = 8,
+    /// Reports that events were coalesced away under overflow; see
+    /// `crate::session::EventBroker`. Carries the running `dropped_events`
+    /// total as an 8-byte little-endian `u64` payload.
+    EventsDropped = 9,
+    /// Carries one plaintext recovered by `skybridge_engine_feed` from a
+    /// complete, in-sequence frame.
+    FrameDecoded = 10,
+    /// Carries the completion of an `_async` call: an 8-byte little-endian
+    /// request id (see `skybridge_engine_connect_async`) followed by a
+    /// 4-byte little-endian `SkybridgeErrorCode`.
+    OperationComplete = 11,
 }
 
 #[repr(C)]
@@ -84,9 +157,36 @@ pub struct SkybridgeStreamMetrics {
     pub packet_loss_ppm: u32,
 }
 
-/// Maximum number of queued events retained by the engine handle.
-/// Older events are dropped once this capacity is reached so callers must poll
-/// regularly to avoid missing notifications.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkybridgeEngineSnapshot {
+    pub state: SkybridgeSessionState,
+    /// Milliseconds since the last heartbeat; meaningless when
+    /// `has_last_heartbeat` is `false`.
+    pub last_heartbeat_ms: u64,
+    pub has_last_heartbeat: bool,
+    pub has_secrets: bool,
+    /// `0` if no handshake has negotiated a version yet.
+    pub negotiated_version: u16,
+    /// Running total of events coalesced away by the handle's `EventBroker`
+    /// under overflow; see `SkybridgeEventKind::EventsDropped`.
+    pub dropped_events: u64,
+    /// Smoothed heartbeat RTT in milliseconds, rounded; meaningless when
+    /// `has_srtt` is `false`.
+    pub srtt_ms: u64,
+    pub has_srtt: bool,
+    /// Deadline `skybridge_engine_check_liveness_auto` is currently
+    /// evaluating against; meaningless when `has_liveness_deadline` is
+    /// `false`.
+    pub liveness_deadline_ms: u64,
+    pub has_liveness_deadline: bool,
+}
+
+/// Maximum number of queued events retained by the engine handle's polling
+/// fallback (see `skybridge_engine_poll_events`). Only used when no callback
+/// is registered via `skybridge_engine_set_event_callback`; once the buffer
+/// is full, older non-critical events are coalesced away and reported via a
+/// single `SkybridgeEventKind::EventsDropped` marker (see `EventBroker`).
 pub const SKYBRIDGE_EVENT_CAPACITY: usize = 1024;
 
 fn map_core_error(err: CoreError) -> SkybridgeErrorCode {
@@ -103,6 +203,20 @@ fn map_core_error(err: CoreError) -> SkybridgeErrorCode {
         CoreError::InvalidCryptoKey => SkybridgeErrorCode::CryptoError,
         CoreError::RateLimited { .. } => SkybridgeErrorCode::RateLimited,
         CoreError::InvalidState { .. } => SkybridgeErrorCode::InvalidState,
+        CoreError::InvalidConfig { .. } => SkybridgeErrorCode::InvalidInput,
+        CoreError::PathValidationFailed => SkybridgeErrorCode::SessionError,
+        CoreError::CircuitOpen { .. } => SkybridgeErrorCode::RateLimited,
+        CoreError::ResumptionRejected { .. } => SkybridgeErrorCode::CryptoError,
+        CoreError::ReconnectExhausted { .. } => SkybridgeErrorCode::SessionError,
+        CoreError::HeartbeatTimeout { .. } => SkybridgeErrorCode::InvalidState,
+        CoreError::FrameSequenceMismatch { .. } => SkybridgeErrorCode::FrameSequenceMismatch,
+        CoreError::UnsupportedProtocol { .. } => SkybridgeErrorCode::UnsupportedProtocol,
+        CoreError::NoHeartbeat => SkybridgeErrorCode::InvalidState,
+        CoreError::IdentityVerification { .. } => SkybridgeErrorCode::CryptoError,
+        CoreError::RotationFailed { .. } => SkybridgeErrorCode::CryptoError,
+        CoreError::CounterExhausted => SkybridgeErrorCode::CryptoError,
+        CoreError::InsufficientShares { .. } => SkybridgeErrorCode::CryptoError,
+        CoreError::ShareVerificationFailed { .. } => SkybridgeErrorCode::CryptoError,
     }
 }
 
@@ -148,6 +262,8 @@ impl AsyncSessionManager for FfiSessionManager {
 struct FfiStreamController {
     last_input: Arc<Mutex<Vec<u8>>>,
     last_rate: Arc<Mutex<Option<FlowRate>>>,
+    last_loss_ppm: Arc<Mutex<u32>>,
+    last_rtt: Arc<Mutex<Duration>>,
 }
 
 impl FfiStreamController {
@@ -155,12 +271,22 @@ impl FfiStreamController {
         Self {
             last_input: buffer,
             last_rate: Arc::new(Mutex::new(None)),
+            last_loss_ppm: Arc::new(Mutex::new(0)),
+            last_rtt: Arc::new(Mutex::new(Duration::ZERO)),
         }
     }
 
     fn record_input(&self, data: &[u8]) {
         *self.last_input.lock().unwrap() = data.to_vec();
     }
+
+    /// Records the latest out-of-band loss/RTT sample reported via
+    /// `skybridge_engine_report_stream_sample`, read back by `metrics` and
+    /// `sample_rtt` on the next `CoreEngine::adaptive_bitrate_step`.
+    fn record_sample(&self, packet_loss_ppm: u32, rtt: Duration) {
+        *self.last_loss_ppm.lock().unwrap() = packet_loss_ppm;
+        *self.last_rtt.lock().unwrap() = rtt;
+    }
 }
 
 #[async_trait::async_trait(?Send)]
@@ -176,13 +302,20 @@ impl StreamController for FfiStreamController {
             .unwrap()
             .map(|r| r.target_bitrate_bps)
             .unwrap_or(0);
+        let loss_ppm = *self.last_loss_ppm.lock().unwrap();
         StreamMetrics {
             bitrate_bps: bitrate,
-            packet_loss: 0.0,
+            packet_loss: loss_ppm as f32 / 1_000_000.0,
         }
     }
 }
 
+impl RttSampler for FfiStreamController {
+    fn sample_rtt(&self) -> Duration {
+        *self.last_rtt.lock().unwrap()
+    }
+}
+
 #[derive(Clone)]
 struct FfiCrypto {
     inner: Arc<P256SessionCrypto<P256KeyExchange>>,
@@ -240,66 +373,148 @@ impl HeartbeatEmitter for FfiHeartbeat {
     }
 }
 
+type FfiEngine = CoreEngine<FfiSessionManager, FfiStreamController, FfiCrypto, FfiHeartbeat>;
+
+/// Job queued onto `AsyncWorker`'s background thread: a closure that drives
+/// one async engine call to completion against that thread's own `Runtime`.
+/// Only the closure itself crosses threads (it's `Send`), not the `!Send`
+/// futures `CoreEngine`'s `async_trait(?Send)` methods return — those are
+/// created and polled entirely on the worker thread.
+type AsyncJob = Box<dyn FnOnce(&Runtime) + Send>;
+
+/// Identifies one in-flight `_async` FFI call; returned immediately by
+/// functions like `skybridge_engine_connect_async` and echoed back in the
+/// `SkybridgeEventKind::OperationComplete` event once it finishes.
+pub type SkybridgeRequestId = u64;
+
+/// Drives `_async` FFI calls on a dedicated OS thread with its own
+/// current-thread Tokio runtime, so a host application's calling thread
+/// (typically a UI thread) never blocks in `Runtime::block_on`. Jobs run
+/// one at a time, in submission order, against the same `Arc<FfiEngine>`
+/// the synchronous FFI functions use, so in-flight async and sync calls
+/// observe consistent state through `EngineState`'s own `Mutex`-guarded
+/// fields. The worker thread exits once `sender` is dropped (i.e. when the
+/// owning `SkybridgeEngineHandle` is freed).
+struct AsyncWorker {
+    sender: mpsc::Sender<AsyncJob>,
+    next_request_id: AtomicU64,
+}
+
+impl AsyncWorker {
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::channel::<AsyncJob>();
+        std::thread::Builder::new()
+            .name("skybridge-async-worker".into())
+            .spawn(move || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_time()
+                    .build()
+                    .expect("async worker runtime");
+                for job in receiver {
+                    job(&runtime);
+                }
+            })
+            .expect("spawn skybridge-async-worker thread");
+        Self {
+            sender,
+            next_request_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Reserves the next request id and queues `job` to run with it. The
+    /// worker thread having panicked and hung up is the only way `send`
+    /// fails; the caller still gets a request id back, it just never sees
+    /// a matching `OperationComplete`, the same way a dropped future would
+    /// never resolve.
+    fn submit(&self, build_job: impl FnOnce(SkybridgeRequestId) -> AsyncJob) -> SkybridgeRequestId {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let _ = self.sender.send(build_job(request_id));
+        request_id
+    }
+}
+
 pub struct SkybridgeEngineHandle {
     runtime: Runtime,
-    engine: CoreEngine<FfiSessionManager, FfiStreamController, FfiCrypto, FfiHeartbeat>,
+    engine: Arc<FfiEngine>,
+    async_worker: AsyncWorker,
     input_buffer: Arc<Mutex<Vec<u8>>>,
-    events: Arc<Mutex<VecDeque<FfiEvent>>>,
+    events: Arc<EventBroker>,
     last_event_payload: Arc<Mutex<Vec<u8>>>,
     last_public_key: Arc<Mutex<Vec<u8>>>,
     last_crypto_output: Arc<Mutex<Vec<u8>>>,
+    /// Set by `skybridge_engine_set_event_callback`; when present, `push_event`
+    /// dispatches synchronously instead of enqueuing into `events`.
+    event_callback: Arc<Mutex<Option<EventCallback>>>,
+    /// UTF-8 diagnostic captured by `record_error` from the most recently
+    /// failing FFI call, retrieved via `skybridge_engine_last_error`.
+    last_error: Arc<Mutex<Vec<u8>>>,
 }
 
 impl SkybridgeEngineHandle {
     fn new() -> Self {
         let input_buffer = Arc::new(Mutex::new(Vec::new()));
-        let events = Arc::new(Mutex::new(VecDeque::new()));
+        let events = Arc::new(EventBroker::new(SKYBRIDGE_EVENT_CAPACITY));
         let last_event_payload = Arc::new(Mutex::new(Vec::new()));
         let last_public_key = Arc::new(Mutex::new(Vec::new()));
         let last_crypto_output = Arc::new(Mutex::new(Vec::new()));
+        let event_callback = Arc::new(Mutex::new(None));
+        let last_error = Arc::new(Mutex::new(Vec::new()));
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_time()
             .build()
             .expect("runtime");
         let session_manager = FfiSessionManager::new();
         let stream_controller = FfiStreamController::new(input_buffer.clone());
-        let engine = CoreEngine::new(
+        let engine = Arc::new(CoreEngine::new(
             session_manager,
             stream_controller,
             FfiCrypto::new(),
             FfiHeartbeat,
-        );
+        ));
+        let async_worker = AsyncWorker::new();
         Self {
             runtime,
             engine,
+            async_worker,
             input_buffer,
             events,
             last_event_payload,
             last_public_key,
             last_crypto_output,
+            event_callback,
+            last_error,
         }
     }
 
-    fn push_event(&self, event: FfiEvent) {
-        let mut queue = self.events.lock().unwrap();
-        if queue.len() >= SKYBRIDGE_EVENT_CAPACITY {
-            queue.pop_front();
-        }
-        queue.push_back(event);
+    /// Records a UTF-8 diagnostic describing `err` as having occurred during
+    /// `operation` (its `CoreError` variant's `Display`, which already
+    /// carries the offending reason/length/context for every variant that
+    /// has one), for later retrieval via `skybridge_engine_last_error`, then
+    /// maps it to the coarse `SkybridgeErrorCode` callers switch on.
+    fn record_error(&self, operation: &str, err: CoreError) -> SkybridgeErrorCode {
+        record_error_into(&self.last_error, operation, err)
+    }
+
+    /// Dispatches `event` to the registered callback, if any, synchronously
+    /// and without ever dropping it; otherwise falls back to enqueuing into
+    /// the polling buffer, where it's subject to `SKYBRIDGE_EVENT_CAPACITY`
+    /// coalescing.
+    fn push_event(&self, event: SessionEvent) {
+        dispatch_event(&self.events, &self.event_callback, event);
     }
 
     fn pop_event(&self) -> SkybridgeEvent {
-        let mut queue = self.events.lock().unwrap();
-        if let Some(event) = queue.pop_front() {
+        if let Some(event) = self.events.poll() {
+            let (kind, data) = session_event_to_ffi(event);
             let mut payload = self.last_event_payload.lock().unwrap();
-            *payload = event.payload;
+            *payload = data;
             let ptr = if payload.is_empty() {
                 std::ptr::null()
             } else {
                 payload.as_ptr()
             };
             SkybridgeEvent {
-                kind: event.kind,
+                kind,
                 data_ptr: ptr,
                 data_len: payload.len(),
             }
@@ -313,7 +528,7 @@ impl SkybridgeEngineHandle {
     }
 
     fn clear_events(&self) {
-        self.events.lock().unwrap().clear();
+        self.events.clear();
         self.last_event_payload.lock().unwrap().clear();
     }
 
@@ -344,6 +559,27 @@ impl SkybridgeEngineHandle {
         SkybridgeErrorCode::Ok
     }
 
+    fn read_last_error(&self, out_buffer: *mut SkybridgeBuffer) -> SkybridgeErrorCode {
+        if out_buffer.is_null() {
+            return SkybridgeErrorCode::InvalidInput;
+        }
+
+        let buffer = self.last_error.lock().unwrap();
+        let view = SkybridgeBuffer {
+            data_ptr: if buffer.is_empty() {
+                std::ptr::null()
+            } else {
+                buffer.as_ptr()
+            },
+            data_len: buffer.len(),
+        };
+
+        unsafe {
+            *out_buffer = view;
+        }
+        SkybridgeErrorCode::Ok
+    }
+
     fn with_handle<T>(
         handle: *mut SkybridgeEngineHandle,
         f: impl FnOnce(&mut SkybridgeEngineHandle) -> T,
@@ -356,10 +592,84 @@ impl SkybridgeEngineHandle {
     }
 }
 
-#[derive(Debug, Clone)]
-struct FfiEvent {
-    kind: SkybridgeEventKind,
-    payload: Vec<u8>,
+/// Records a UTF-8 diagnostic describing `err` as having occurred during
+/// `operation` into `last_error`, for later retrieval via
+/// `skybridge_engine_last_error`, then maps it to the coarse
+/// `SkybridgeErrorCode` callers switch on. Shared by
+/// `SkybridgeEngineHandle::record_error` and `AsyncWorker`'s jobs, since an
+/// `_async` call's failure needs to land in the same diagnostic buffer a
+/// synchronous call's failure does.
+fn record_error_into(
+    last_error: &Mutex<Vec<u8>>,
+    operation: &str,
+    err: CoreError,
+) -> SkybridgeErrorCode {
+    *last_error.lock().unwrap() = format!("{operation}: {err}").into_bytes();
+    map_core_error(err)
+}
+
+/// Dispatches `event` to `callback`, if one is registered, synchronously and
+/// without ever dropping it; otherwise falls back to enqueuing into `events`,
+/// where it's subject to `SKYBRIDGE_EVENT_CAPACITY` coalescing. Shared by
+/// `SkybridgeEngineHandle::push_event` and `AsyncWorker`'s jobs, since an
+/// `_async` call's `OperationComplete` event needs to reach the same sink a
+/// synchronous call's events do.
+///
+/// The registered `(callback, user_data)` pair is copied out of `callback`'s
+/// guard, and the guard is dropped, before the callback is actually invoked:
+/// `callback` is a plain `std::sync::Mutex`, so a callback that reenters any
+/// `skybridge_engine_*` function on this handle — e.g. to re-register itself
+/// via `skybridge_engine_set_event_callback`, or to trigger another
+/// `dispatch_event` on the same thread — would deadlock permanently if the
+/// lock were still held across the call.
+fn dispatch_event(
+    events: &EventBroker,
+    callback: &Mutex<Option<EventCallback>>,
+    event: SessionEvent,
+) {
+    let registered = callback.lock().unwrap().as_ref().map(
+        |EventCallback { callback, user_data }| (*callback, *user_data),
+    );
+    if let Some((callback, user_data)) = registered {
+        let (kind, data) = session_event_to_ffi(event);
+        let data_ptr = if data.is_empty() {
+            std::ptr::null()
+        } else {
+            data.as_ptr()
+        };
+        callback(kind, data_ptr, data.len(), user_data);
+        return;
+    }
+    events.push(event);
+}
+
+/// Flattens a [`SessionEvent`] into the `(kind, payload)` pair the C ABI
+/// exposes through [`SkybridgeEvent`].
+fn session_event_to_ffi(event: SessionEvent) -> (SkybridgeEventKind, Vec<u8>) {
+    match event {
+        SessionEvent::Connected => (SkybridgeEventKind::Connected, Vec::new()),
+        SessionEvent::Disconnected => (SkybridgeEventKind::Disconnected, Vec::new()),
+        SessionEvent::HeartbeatAck => (SkybridgeEventKind::HeartbeatAck, Vec::new()),
+        SessionEvent::InputReceived(data) => (SkybridgeEventKind::InputReceived, data),
+        SessionEvent::FrameDecoded(data) => (SkybridgeEventKind::FrameDecoded, data),
+        SessionEvent::Reconnected => (SkybridgeEventKind::Reconnected, Vec::new()),
+        SessionEvent::ReconnectExhausted => (SkybridgeEventKind::ReconnectExhausted, Vec::new()),
+        SessionEvent::HeartbeatTimeout => (SkybridgeEventKind::HeartbeatTimeout, Vec::new()),
+        SessionEvent::BitrateChanged { target_bitrate_bps } => (
+            SkybridgeEventKind::BitrateChanged,
+            target_bitrate_bps.to_le_bytes().to_vec(),
+        ),
+        SessionEvent::EventsDropped { count } => (
+            SkybridgeEventKind::EventsDropped,
+            count.to_le_bytes().to_vec(),
+        ),
+        SessionEvent::OperationComplete { request_id, error_code } => {
+            let mut payload = Vec::with_capacity(12);
+            payload.extend_from_slice(&request_id.to_le_bytes());
+            payload.extend_from_slice(&error_code.to_le_bytes());
+            (SkybridgeEventKind::OperationComplete, payload)
+        }
+    }
 }
 
 #[no_mangle]
@@ -405,10 +715,52 @@ fn parse_config(config: SkybridgeSessionConfig) -> Result<SessionConfig, Skybrid
             .to_vec(),
         )
     };
+    let reconnect_strategy = if config.reconnect_max_delay_ms == 0 {
+        None
+    } else {
+        Some(crate::session::ReconnectStrategy {
+            initial_delay: std::time::Duration::from_millis(config.reconnect_base_delay_ms),
+            multiplier: 2.0,
+            max_delay: std::time::Duration::from_millis(config.reconnect_max_delay_ms),
+            max_attempts: if config.reconnect_max_retries == 0 {
+                None
+            } else {
+                Some(config.reconnect_max_retries)
+            },
+        })
+    };
+    let adaptive_liveness = if config.adaptive_liveness_max_deadline_ms == 0 {
+        None
+    } else {
+        let default = crate::session::AdaptiveLivenessConfig::default();
+        Some(crate::session::AdaptiveLivenessConfig {
+            min_deadline_ms: config.adaptive_liveness_min_deadline_ms,
+            max_deadline_ms: config.adaptive_liveness_max_deadline_ms,
+            fallback_multiplier: if config.adaptive_liveness_fallback_multiplier == 0 {
+                default.fallback_multiplier
+            } else {
+                config.adaptive_liveness_fallback_multiplier
+            },
+        })
+    };
     Ok(SessionConfig {
         client_id,
         heartbeat_interval_ms: config.heartbeat_interval_ms,
         peer_public_key,
+        // FFI sessions always run the loss/RTT-driven AIMD loop; hosts feed
+        // it samples via `skybridge_engine_report_stream_sample`.
+        abr_config: Some(AbrConfig::default()),
+        crypto_pool: None,
+        reconnect_strategy,
+        protocol_version: config.protocol_version,
+        min_supported: config.min_supported,
+        adaptive_liveness,
+        // The FFI config struct has no C-ABI field for threshold custody yet;
+        // threshold mode is only reachable from Rust callers constructing
+        // `SessionConfig` directly with a `ThresholdSessionCrypto` provider.
+        threshold_params: None,
+        peer_identity: None,
+        peer_suite_preference: None,
     })
 }
 
@@ -422,34 +774,200 @@ pub extern "C" fn skybridge_engine_connect(
             .runtime
             .block_on(handle.engine.initialize(config))
             .map(|_| {
-                handle.push_event(FfiEvent {
-                    kind: SkybridgeEventKind::Connected,
-                    payload: Vec::new(),
-                });
+                handle.push_event(SessionEvent::Connected);
                 SkybridgeErrorCode::Ok
             })
-            .unwrap_or_else(map_core_error),
+            .unwrap_or_else(|err| handle.record_error("connect", err)),
         Err(code) => code,
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
 
+#[no_mangle]
+/// Like `skybridge_engine_connect`, but returns a `SkybridgeRequestId`
+/// immediately instead of blocking the calling thread: `config` is parsed
+/// synchronously (it holds raw pointers that can't outlive this call), and
+/// the handshake itself runs on `AsyncWorker`'s dedicated thread. Once it
+/// finishes, a `SessionEvent::Connected` (on success) is dispatched followed
+/// by a `SkybridgeEventKind::OperationComplete` carrying this request id and
+/// the resulting `SkybridgeErrorCode`, through whichever sink
+/// `skybridge_engine_set_event_callback`/`skybridge_engine_poll_events`
+/// would otherwise use.
+pub extern "C" fn skybridge_engine_connect_async(
+    handle: *mut SkybridgeEngineHandle,
+    config: SkybridgeSessionConfig,
+) -> SkybridgeRequestId {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        let config = match parse_config(config) {
+            Ok(config) => config,
+            Err(code) => {
+                let events = handle.events.clone();
+                let event_callback = handle.event_callback.clone();
+                return handle.async_worker.submit(move |request_id| {
+                    Box::new(move |_runtime| {
+                        dispatch_event(
+                            &events,
+                            &event_callback,
+                            SessionEvent::OperationComplete {
+                                request_id,
+                                error_code: code as i32,
+                            },
+                        );
+                    })
+                });
+            }
+        };
+        let engine = handle.engine.clone();
+        let events = handle.events.clone();
+        let event_callback = handle.event_callback.clone();
+        let last_error = handle.last_error.clone();
+        handle.async_worker.submit(move |request_id| {
+            Box::new(move |runtime| {
+                let error_code = match runtime.block_on(engine.initialize(config)) {
+                    Ok(()) => {
+                        dispatch_event(&events, &event_callback, SessionEvent::Connected);
+                        SkybridgeErrorCode::Ok
+                    }
+                    Err(err) => record_error_into(&last_error, "connect_async", err),
+                };
+                dispatch_event(
+                    &events,
+                    &event_callback,
+                    SessionEvent::OperationComplete {
+                        request_id,
+                        error_code: error_code as i32,
+                    },
+                );
+            })
+        })
+    })
+    .unwrap_or(0)
+}
+
 #[no_mangle]
 pub extern "C" fn skybridge_engine_reconnect(
     handle: *mut SkybridgeEngineHandle,
 ) -> SkybridgeErrorCode {
     SkybridgeEngineHandle::with_handle(handle, |handle| {
-        handle
-            .runtime
-            .block_on(handle.engine.reconnect())
-            .map(|_| {
-                handle.push_event(FfiEvent {
-                    kind: SkybridgeEventKind::Reconnected,
-                    payload: Vec::new(),
-                });
+        match handle.runtime.block_on(handle.engine.reconnect()) {
+            Ok(()) => {
+                handle.push_event(SessionEvent::Reconnected);
                 SkybridgeErrorCode::Ok
-            })
-            .unwrap_or_else(map_core_error)
+            }
+            Err(err @ CoreError::ReconnectExhausted { .. }) => {
+                handle.push_event(SessionEvent::ReconnectExhausted);
+                handle.record_error("reconnect", err)
+            }
+            Err(err) => handle.record_error("reconnect", err),
+        }
+    })
+    .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
+#[no_mangle]
+/// Returns the delay (ms) the engine is currently sleeping through before its
+/// next reconnect attempt, or `u64::MAX` if no backoff sleep is in progress.
+pub extern "C" fn skybridge_engine_next_reconnect_delay_ms(
+    handle: *mut SkybridgeEngineHandle,
+) -> u64 {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        handle.engine.next_reconnect_delay_ms().unwrap_or(u64::MAX)
+    })
+    .unwrap_or(u64::MAX)
+}
+
+#[no_mangle]
+/// Returns the wire-protocol version negotiated during the last successful
+/// `skybridge_engine_connect` — `SkybridgeSessionConfig::protocol_version`
+/// capped at this build's `skybridge_core::PROTOCOL_VERSION_MAX` — or `0` if
+/// no handshake has completed.
+pub extern "C" fn skybridge_engine_negotiated_version(handle: *mut SkybridgeEngineHandle) -> u16 {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        handle.engine.negotiated_version().unwrap_or(0)
+    })
+    .unwrap_or(0)
+}
+
+#[no_mangle]
+/// Checks the last heartbeat against `missed_interval_tolerance` heartbeat
+/// intervals. Pushes a `HeartbeatTimeout` event and returns
+/// `SkybridgeErrorCode::InvalidState` if the gap has been exceeded.
+pub extern "C" fn skybridge_engine_check_liveness(
+    handle: *mut SkybridgeEngineHandle,
+    missed_interval_tolerance: u32,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        match handle.engine.check_liveness(missed_interval_tolerance) {
+            Ok(()) => SkybridgeErrorCode::Ok,
+            Err(err @ CoreError::HeartbeatTimeout { .. }) => {
+                handle.push_event(SessionEvent::HeartbeatTimeout);
+                handle.record_error("check_liveness", err)
+            }
+            Err(err) => handle.record_error("check_liveness", err),
+        }
+    })
+    .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
+#[no_mangle]
+/// Like `skybridge_engine_check_liveness`, but derives the tolerance window
+/// from the measured heartbeat RTT (see `SkybridgeEngineSnapshot::srtt_ms`)
+/// instead of a fixed interval multiple. Pushes a `HeartbeatTimeout` event
+/// and returns `SkybridgeErrorCode::InvalidState` if the deadline has been
+/// exceeded.
+pub extern "C" fn skybridge_engine_check_liveness_auto(
+    handle: *mut SkybridgeEngineHandle,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        match handle.engine.check_liveness_auto() {
+            Ok(()) => SkybridgeErrorCode::Ok,
+            Err(err @ CoreError::HeartbeatTimeout { .. }) => {
+                handle.push_event(SessionEvent::HeartbeatTimeout);
+                handle.record_error("check_liveness_auto", err)
+            }
+            Err(err) => handle.record_error("check_liveness_auto", err),
+        }
+    })
+    .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
+#[no_mangle]
+/// Fills `out_snapshot` with a point-in-time view of engine state.
+///
+/// # Safety
+/// `out_snapshot` must point to valid, writable memory for a
+/// `SkybridgeEngineSnapshot`.
+pub unsafe extern "C" fn skybridge_engine_snapshot(
+    handle: *mut SkybridgeEngineHandle,
+    out_snapshot: *mut SkybridgeEngineSnapshot,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        if out_snapshot.is_null() {
+            return SkybridgeErrorCode::InvalidInput;
+        }
+        let snapshot = handle.engine.snapshot();
+        let state = match snapshot.state {
+            SessionState::Disconnected => SkybridgeSessionState::Disconnected,
+            SessionState::Connecting => SkybridgeSessionState::Connecting,
+            SessionState::Connected => SkybridgeSessionState::Connected,
+            SessionState::Reconnecting => SkybridgeSessionState::Reconnecting,
+            SessionState::ShuttingDown => SkybridgeSessionState::ShuttingDown,
+        };
+        unsafe {
+            *out_snapshot = SkybridgeEngineSnapshot {
+                state,
+                last_heartbeat_ms: snapshot.last_heartbeat_elapsed_ms.unwrap_or(0),
+                has_last_heartbeat: snapshot.last_heartbeat_elapsed_ms.is_some(),
+                has_secrets: snapshot.has_secrets,
+                negotiated_version: snapshot.negotiated_version.unwrap_or(0),
+                dropped_events: handle.events.dropped_events(),
+                srtt_ms: snapshot.srtt_ms.map(|ms| ms.round() as u64).unwrap_or(0),
+                has_srtt: snapshot.srtt_ms.is_some(),
+                liveness_deadline_ms: snapshot.liveness_deadline_ms.unwrap_or(0),
+                has_liveness_deadline: snapshot.liveness_deadline_ms.is_some(),
+            };
+        }
+        SkybridgeErrorCode::Ok
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
@@ -493,7 +1011,7 @@ pub unsafe extern "C" fn skybridge_engine_local_public_key(
                 }
                 SkybridgeErrorCode::Ok
             }
-            Err(err) => map_core_error(err),
+            Err(err) => handle.record_error("local_public_key", err),
         }
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
@@ -508,13 +1026,10 @@ pub extern "C" fn skybridge_engine_send_heartbeat(
             .runtime
             .block_on(handle.engine.send_heartbeat())
             .map(|_| {
-                handle.push_event(FfiEvent {
-                    kind: SkybridgeEventKind::HeartbeatAck,
-                    payload: Vec::new(),
-                });
+                handle.push_event(SessionEvent::HeartbeatAck);
                 SkybridgeErrorCode::Ok
             })
-            .unwrap_or_else(map_core_error)
+            .unwrap_or_else(|err| handle.record_error("send_heartbeat", err))
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
@@ -560,6 +1075,61 @@ pub unsafe extern "C" fn skybridge_engine_metrics(
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
 
+/// Minimum fractional change in the AIMD target, relative to the previous
+/// target, before `skybridge_engine_report_stream_sample` bothers emitting a
+/// `BitrateChanged` event.
+const BITRATE_CHANGE_HYSTERESIS_RATIO: f32 = 0.05;
+
+fn bitrate_changed_enough(previous: u64, updated: u64) -> bool {
+    if previous == 0 {
+        return updated != 0;
+    }
+    let delta = (updated as f32 - previous as f32).abs();
+    delta / previous as f32 > BITRATE_CHANGE_HYSTERESIS_RATIO
+}
+
+#[no_mangle]
+/// Feeds a loss/RTT sample into the session's AIMD controller (see
+/// `stream::AdaptiveBitrateController`) and applies the resulting flow rate,
+/// pushing a `BitrateChanged` event if the target moved by more than
+/// `BITRATE_CHANGE_HYSTERESIS_RATIO`.
+pub extern "C" fn skybridge_engine_report_stream_sample(
+    handle: *mut SkybridgeEngineHandle,
+    packet_loss_ppm: u32,
+    rtt_ms: u32,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        handle
+            .engine
+            .stream_controller
+            .record_sample(packet_loss_ppm, Duration::from_millis(rtt_ms as u64));
+        let previous = handle
+            .engine
+            .state
+            .last_flow_rate
+            .lock()
+            .unwrap()
+            .map(|rate| rate.target_bitrate_bps)
+            .unwrap_or(0);
+        let stream_controller = handle.engine.stream_controller.clone();
+        match handle
+            .runtime
+            .block_on(handle.engine.adaptive_bitrate_step(&stream_controller))
+        {
+            Ok(rate) => {
+                if bitrate_changed_enough(previous, rate.target_bitrate_bps) {
+                    handle.push_event(SessionEvent::BitrateChanged {
+                        target_bitrate_bps: rate.target_bitrate_bps,
+                    });
+                }
+                SkybridgeErrorCode::Ok
+            }
+            Err(err) => handle.record_error("report_stream_sample", err),
+        }
+    })
+    .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
 #[no_mangle]
 /// # Safety
 /// The caller must provide a valid engine handle and, when `input_len > 0`, a non-null pointer
@@ -579,10 +1149,7 @@ pub unsafe extern "C" fn skybridge_engine_send_input(
             std::slice::from_raw_parts(input_ptr, input_len)
         };
         handle.engine.stream_controller.record_input(data);
-        handle.push_event(FfiEvent {
-            kind: SkybridgeEventKind::InputReceived,
-            payload: data.to_vec(),
-        });
+        handle.push_event(SessionEvent::InputReceived(data.to_vec()));
         SkybridgeErrorCode::Ok
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
@@ -597,13 +1164,10 @@ pub extern "C" fn skybridge_engine_shutdown(
             .runtime
             .block_on(handle.engine.shutdown())
             .map(|_| {
-                handle.push_event(FfiEvent {
-                    kind: SkybridgeEventKind::Disconnected,
-                    payload: Vec::new(),
-                });
+                handle.push_event(SessionEvent::Disconnected);
                 SkybridgeErrorCode::Ok
             })
-            .unwrap_or_else(map_core_error)
+            .unwrap_or_else(|err| handle.record_error("shutdown", err))
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
@@ -638,7 +1202,10 @@ pub unsafe extern "C" fn skybridge_engine_encrypt_payload(
             .engine
             .encrypt_payload(plaintext)
             .map(|ciphertext| handle.write_crypto_output(ciphertext, out_buffer))
-            .unwrap_or_else(map_core_error)
+            .unwrap_or_else(|err| {
+                let op = format!("encrypt_payload (plaintext_len={plaintext_len})");
+                handle.record_error(&op, err)
+            })
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
@@ -666,7 +1233,106 @@ pub unsafe extern "C" fn skybridge_engine_decrypt_payload(
             .engine
             .decrypt_payload(ciphertext)
             .map(|plaintext| handle.write_crypto_output(plaintext, out_buffer))
-            .unwrap_or_else(map_core_error)
+            .unwrap_or_else(|err| {
+                let op = format!("decrypt_payload (ciphertext_len={ciphertext_len})");
+                handle.record_error(&op, err)
+            })
+    })
+    .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
+#[no_mangle]
+/// Seals `plaintext` into one self-describing, sequence-numbered frame (see
+/// `crate::frame`) and writes it to `out_buffer`. Unlike `skybridge_engine_encrypt_payload`,
+/// the result is meant to be sent as-is over a byte stream with no transport-level framing
+/// of its own; the peer recovers message boundaries via `skybridge_engine_feed`.
+///
+/// # Safety
+/// `out_buffer` must be a valid, writable pointer to `SkybridgeBuffer`. The returned pointer
+/// remains valid until the next call to encode/decode or the engine handle is freed.
+pub unsafe extern "C" fn skybridge_engine_encode_frame(
+    handle: *mut SkybridgeEngineHandle,
+    plaintext_ptr: *const u8,
+    plaintext_len: usize,
+    out_buffer: *mut SkybridgeBuffer,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        if plaintext_len > 0 && plaintext_ptr.is_null() {
+            return SkybridgeErrorCode::InvalidInput;
+        }
+        let plaintext = if plaintext_len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(plaintext_ptr, plaintext_len) }
+        };
+        handle
+            .engine
+            .encode_frame(plaintext)
+            .map(|framed| handle.write_crypto_output(framed, out_buffer))
+            .unwrap_or_else(|err| {
+                let op = format!("encode_frame (plaintext_len={plaintext_len})");
+                handle.record_error(&op, err)
+            })
+    })
+    .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
+#[no_mangle]
+/// Appends `len` bytes at `bytes_ptr` to the engine's frame reassembly buffer and decrypts
+/// every frame that's now complete, pushing one `SkybridgeEventKind::FrameDecoded` event per
+/// decrypted plaintext (retrieve them via `skybridge_engine_poll_events` or the registered
+/// callback). Partial frames remain buffered for a future call. Stops at, and reports, the
+/// first malformed or out-of-sequence frame; bytes fed before it are still decoded and
+/// events pushed for them.
+///
+/// # Safety
+/// `bytes_ptr` must point to at least `len` readable bytes, or `len` must be `0`.
+pub unsafe extern "C" fn skybridge_engine_feed(
+    handle: *mut SkybridgeEngineHandle,
+    bytes_ptr: *const u8,
+    len: usize,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        if len > 0 && bytes_ptr.is_null() {
+            return SkybridgeErrorCode::InvalidInput;
+        }
+        let bytes = if len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(bytes_ptr, len) }
+        };
+        match handle.engine.feed_frames(bytes) {
+            Ok(frames) => {
+                for frame in frames {
+                    handle.push_event(SessionEvent::FrameDecoded(frame));
+                }
+                SkybridgeErrorCode::Ok
+            }
+            Err(err) => {
+                let op = format!("feed (len={len})");
+                handle.record_error(&op, err)
+            }
+        }
+    })
+    .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
+/// Registers `callback` to receive every `SessionEvent` synchronously, at the
+/// moment it's produced, instead of it being buffered for
+/// `skybridge_engine_poll_events`. Pass `None` to unregister and fall back
+/// to the polling path. The payload pointer handed to `callback` is valid
+/// only for the duration of that call; copy it out if it's needed
+/// afterward.
+#[no_mangle]
+pub extern "C" fn skybridge_engine_set_event_callback(
+    handle: *mut SkybridgeEngineHandle,
+    callback: Option<SkybridgeEventCallbackFn>,
+    user_data: *mut c_void,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| {
+        *handle.event_callback.lock().unwrap() =
+            callback.map(|callback| EventCallback { callback, user_data });
+        SkybridgeErrorCode::Ok
     })
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
@@ -682,6 +1348,24 @@ pub extern "C" fn skybridge_engine_clear_events(
     .unwrap_or(SkybridgeErrorCode::NullHandle)
 }
 
+#[no_mangle]
+/// Writes a view of the UTF-8 diagnostic captured from the most recently
+/// failing FFI call into `out_buffer`, describing the originating operation,
+/// the underlying `CoreError` variant, and any context it carries (offending
+/// length, retry delay, etc.). Empty if no call has failed yet.
+///
+/// # Safety
+/// `out_buffer` must be a valid, writable pointer to `SkybridgeBuffer`. The returned
+/// `data_ptr` remains valid until the next failing FFI call on this handle or until
+/// the handle is freed.
+pub unsafe extern "C" fn skybridge_engine_last_error(
+    handle: *mut SkybridgeEngineHandle,
+    out_buffer: *mut SkybridgeBuffer,
+) -> SkybridgeErrorCode {
+    SkybridgeEngineHandle::with_handle(handle, |handle| handle.read_last_error(out_buffer))
+        .unwrap_or(SkybridgeErrorCode::NullHandle)
+}
+
 #[no_mangle]
 pub extern "C" fn skybridge_engine_state(
     handle: *mut SkybridgeEngineHandle,
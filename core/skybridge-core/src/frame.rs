@@ -0,0 +1,213 @@
+//! Length-prefixed, sequence-authenticated frame codec for the C FFI byte-stream boundary.
+//!
+//! Complements `record::RecordLayer` (which hides true length behind a padding ladder for an
+//! `AsyncRead`/`AsyncWrite` transport) with a framing scheme suited to a caller feeding raw
+//! bytes off a socket into `ffi::skybridge_engine_feed`: every frame carries an explicit,
+//! monotonically increasing sequence number so a reassembler can detect replayed or
+//! reordered frames, independent of whatever ordering guarantees the transport itself makes.
+
+use crate::crypto::{SessionCryptoProvider, SessionSecrets};
+use crate::error::CoreError;
+use std::collections::VecDeque;
+
+/// `4`-byte big-endian plaintext length + `8`-byte big-endian sequence number, prepended to
+/// the plaintext before sealing. This crate's `SessionCryptoProvider::encrypt` has no
+/// separate associated-data parameter, so the header rides inside the sealed payload
+/// instead and is authenticated by the same AEAD tag as the plaintext — the same trick
+/// `RecordLayer` uses to authenticate its own length prefix.
+const FRAME_HEADER_LEN: usize = 4 + 8;
+
+/// Both AEAD suites this crate supports append a 16-byte authentication tag.
+const AEAD_TAG_OVERHEAD: usize = 16;
+
+/// Upper bound on the plaintext `encode_frame`/`decode_ready_frames` will accept, so a
+/// forged length prefix can't be used to force an unbounded allocation ahead of AEAD
+/// verification.
+pub const MAX_FRAME_PLAINTEXT_LEN: usize = 16 * 1024 * 1024;
+
+/// Upper bound on the sealed bytes a single frame declares, derived from
+/// `MAX_FRAME_PLAINTEXT_LEN` plus the header and AEAD tag it's sealed alongside.
+const MAX_SEALED_FRAME_LEN: usize = MAX_FRAME_PLAINTEXT_LEN + FRAME_HEADER_LEN + AEAD_TAG_OVERHEAD;
+
+/// Seals and reassembles frames over a `SessionCryptoProvider` and its negotiated
+/// `SessionSecrets`. Stateless with respect to sequence numbers: callers supply and
+/// advance their own send/receive counters (see `EngineState`'s `frame_send_sequence`/
+/// `frame_recv_sequence`), since those must outlive any one `FrameCodec` and survive a
+/// reconnect.
+pub struct FrameCodec<'a, P: SessionCryptoProvider> {
+    crypto: &'a P,
+    secrets: &'a SessionSecrets,
+}
+
+impl<'a, P: SessionCryptoProvider> FrameCodec<'a, P> {
+    pub fn new(crypto: &'a P, secrets: &'a SessionSecrets) -> Self {
+        Self { crypto, secrets }
+    }
+
+    /// Seals `plaintext` under `sequence` into one complete framed buffer: a 4-byte
+    /// big-endian sealed-length prefix followed by the sealed header-plus-plaintext.
+    pub fn encode_frame(&self, sequence: u64, plaintext: &[u8]) -> Result<Vec<u8>, CoreError> {
+        if plaintext.len() > MAX_FRAME_PLAINTEXT_LEN {
+            return Err(CoreError::Encrypt(format!(
+                "plaintext of {} bytes exceeds MAX_FRAME_PLAINTEXT_LEN ({MAX_FRAME_PLAINTEXT_LEN})",
+                plaintext.len()
+            )));
+        }
+
+        let mut header_and_body = Vec::with_capacity(FRAME_HEADER_LEN + plaintext.len());
+        header_and_body.extend_from_slice(&(plaintext.len() as u32).to_be_bytes());
+        header_and_body.extend_from_slice(&sequence.to_be_bytes());
+        header_and_body.extend_from_slice(plaintext);
+
+        let sealed = self.crypto.encrypt(self.secrets, &header_and_body)?;
+        let prefix_len = u32::try_from(sealed.len())
+            .map_err(|_| CoreError::Encrypt("sealed frame too large to prefix".into()))?;
+
+        let mut framed = Vec::with_capacity(4 + sealed.len());
+        framed.extend_from_slice(&prefix_len.to_be_bytes());
+        framed.extend_from_slice(&sealed);
+        Ok(framed)
+    }
+
+    /// Pulls every complete frame out of `reassembly`, opening each one and then
+    /// verifying its authenticated sequence number equals `*expected_sequence`,
+    /// advancing `*expected_sequence` by one when it does. A frame whose sequence
+    /// doesn't match is rejected with `CoreError::FrameSequenceMismatch`; by then it
+    /// has already been drained from `reassembly` and opened, so it is not retried.
+    /// Partial frames remain buffered for a future call. Returns the decrypted
+    /// plaintext of every frame that completed this call, in order; on any error,
+    /// the plaintexts of frames decoded earlier in the same call are not returned.
+    pub fn decode_ready_frames(
+        &self,
+        reassembly: &mut VecDeque<u8>,
+        expected_sequence: &mut u64,
+    ) -> Result<Vec<Vec<u8>>, CoreError> {
+        let mut decoded = Vec::new();
+        loop {
+            if reassembly.len() < 4 {
+                return Ok(decoded);
+            }
+            let mut prefix = [0u8; 4];
+            for (slot, byte) in prefix.iter_mut().zip(reassembly.iter()) {
+                *slot = *byte;
+            }
+            let sealed_len = u32::from_be_bytes(prefix) as usize;
+            if sealed_len > MAX_SEALED_FRAME_LEN {
+                return Err(CoreError::Decrypt(format!(
+                    "frame length {sealed_len} exceeds the \
+                     {MAX_SEALED_FRAME_LEN}-byte max-frame guard"
+                )));
+            }
+            if reassembly.len() < 4 + sealed_len {
+                return Ok(decoded);
+            }
+
+            reassembly.drain(..4);
+            let sealed: Vec<u8> = reassembly.drain(..sealed_len).collect();
+
+            let header_and_body = self.crypto.decrypt(self.secrets, &sealed)?;
+            if header_and_body.len() < FRAME_HEADER_LEN {
+                return Err(CoreError::Decrypt("frame shorter than its header".into()));
+            }
+            let (header, body) = header_and_body.split_at(FRAME_HEADER_LEN);
+            let declared_len = u32::from_be_bytes(header[..4].try_into().unwrap()) as usize;
+            let sequence = u64::from_be_bytes(header[4..12].try_into().unwrap());
+
+            if declared_len != body.len() {
+                return Err(CoreError::Decrypt(
+                    "frame's authenticated length does not match its sealed body".into(),
+                ));
+            }
+            if sequence != *expected_sequence {
+                return Err(CoreError::FrameSequenceMismatch {
+                    expected: *expected_sequence,
+                    actual: sequence,
+                });
+            }
+            *expected_sequence = expected_sequence
+                .checked_add(1)
+                .ok_or(CoreError::CounterExhausted)?;
+
+            decoded.push(body.to_vec());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{KeyExchangeProvider, P256KeyExchange, P256SessionCrypto};
+
+    async fn handshake_pair() -> (
+        (P256SessionCrypto<P256KeyExchange>, SessionSecrets),
+        (P256SessionCrypto<P256KeyExchange>, SessionSecrets),
+    ) {
+        let local_crypto = P256SessionCrypto::new(P256KeyExchange);
+        let remote_crypto = P256SessionCrypto::new(P256KeyExchange);
+
+        let local_pub = local_crypto.begin_handshake().await.unwrap();
+        let remote_pub = remote_crypto.begin_handshake().await.unwrap();
+        let local_secrets = local_crypto.finalize_handshake(&remote_pub).await.unwrap();
+        let remote_secrets = remote_crypto.finalize_handshake(&local_pub).await.unwrap();
+
+        ((local_crypto, local_secrets), (remote_crypto, remote_secrets))
+    }
+
+    #[tokio::test]
+    async fn frame_round_trips_through_partial_feeds() {
+        let ((sender, sender_secrets), (receiver, receiver_secrets)) = handshake_pair().await;
+        let sender_codec = FrameCodec::new(&sender, &sender_secrets);
+        let receiver_codec = FrameCodec::new(&receiver, &receiver_secrets);
+
+        let framed = sender_codec.encode_frame(0, b"hello").unwrap();
+        let mut reassembly = VecDeque::new();
+        let mut expected_sequence = 0u64;
+
+        // Feed the frame in two pieces to prove partial frames stay buffered.
+        let (first_half, second_half) = framed.split_at(framed.len() / 2);
+        reassembly.extend(first_half);
+        let decoded = receiver_codec
+            .decode_ready_frames(&mut reassembly, &mut expected_sequence)
+            .unwrap();
+        assert!(decoded.is_empty());
+
+        reassembly.extend(second_half);
+        let decoded = receiver_codec
+            .decode_ready_frames(&mut reassembly, &mut expected_sequence)
+            .unwrap();
+        assert_eq!(decoded, vec![b"hello".to_vec()]);
+        assert_eq!(expected_sequence, 1);
+        assert!(reassembly.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reordered_sequence_is_rejected() {
+        let ((sender, sender_secrets), (receiver, receiver_secrets)) = handshake_pair().await;
+        let sender_codec = FrameCodec::new(&sender, &sender_secrets);
+        let receiver_codec = FrameCodec::new(&receiver, &receiver_secrets);
+
+        let framed = sender_codec.encode_frame(1, b"second").unwrap();
+        let mut reassembly = VecDeque::from(framed);
+        let mut expected_sequence = 0u64;
+
+        let err = receiver_codec
+            .decode_ready_frames(&mut reassembly, &mut expected_sequence)
+            .expect_err("sequence 1 before sequence 0 should be rejected");
+        assert!(matches!(err, CoreError::FrameSequenceMismatch { expected: 0, actual: 1 }));
+    }
+
+    #[tokio::test]
+    async fn oversized_length_prefix_is_rejected_before_allocating() {
+        let ((_, _), (receiver, receiver_secrets)) = handshake_pair().await;
+        let codec = FrameCodec::new(&receiver, &receiver_secrets);
+
+        let mut reassembly = VecDeque::new();
+        reassembly.extend((MAX_SEALED_FRAME_LEN as u32 + 1).to_be_bytes());
+        let mut expected_sequence = 0u64;
+
+        let err = codec
+            .decode_ready_frames(&mut reassembly, &mut expected_sequence)
+            .expect_err("length prefix past the max-frame guard should fail");
+        assert!(matches!(err, CoreError::Decrypt(_)));
+    }
+}
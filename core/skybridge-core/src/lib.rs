@@ -5,16 +5,44 @@
 pub mod crypto;
 pub mod error;
 pub mod ffi;
+pub mod frame;
+pub mod pool;
+pub mod record;
 pub mod session;
 pub mod stream;
+pub mod threshold;
+pub mod ticket;
+pub mod transport;
 
 use crypto::SessionCryptoProvider;
+use frame::FrameCodec;
+use pool::CryptoWorkerPool;
+use rand_core::{OsRng, RngCore};
+use record::RecordLayer;
 use session::{
-    AsyncSessionManager, HeartbeatEmitter, SessionConfig, SessionState, SessionStateMachine,
+    AsyncSessionManager, BreakerRegistry, HeartbeatEmitter, LivenessConfig, LivenessFailure,
+    ReconnectStrategy, RttEstimator, SessionConfig, SessionState, SessionStateMachine,
 };
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use stream::{FlowRate, StreamController, StreamMetrics};
+use stream::{AdaptiveBitrateController, FlowRate, RttSampler, StreamController, StreamMetrics};
+use ticket::{ResumptionTicket, TicketAuthority};
+use tokio::io::{AsyncRead, AsyncWrite};
+
+/// Tags the first byte of `encrypt_payload`'s output so `decrypt_payload`
+/// knows whether the rest is a single AEAD frame or a chunked one.
+const PAYLOAD_FRAME_SINGLE: u8 = 0;
+const PAYLOAD_FRAME_CHUNKED: u8 = 1;
+/// A key-rotation control frame: the body is an ephemeral public key to be
+/// fed into `SessionCryptoProvider::complete_rotation`, never application
+/// plaintext. See `CoreEngine::begin_rotation`/`complete_rotation`.
+const PAYLOAD_FRAME_ROTATION: u8 = 2;
+
+/// Highest wire-protocol version this build of the engine can speak.
+/// `CoreEngine::initialize` negotiates down to
+/// `min(config.protocol_version, PROTOCOL_VERSION_MAX)` and rejects configs
+/// whose `min_supported` exceeds it, since no common version would exist.
+pub const PROTOCOL_VERSION_MAX: u16 = 2;
 
 /// CoreEngine ties together session, streaming, and crypto primitives.
 #[derive(Debug)]
@@ -22,7 +50,32 @@ pub struct EngineState {
     state_machine: SessionStateMachine,
     last_config: Mutex<Option<SessionConfig>>,
     last_heartbeat: Mutex<Option<Instant>>,
+    rtt_estimator: Mutex<Option<RttEstimator>>,
     session_secrets: Mutex<Option<crypto::SessionSecrets>>,
+    breakers: BreakerRegistry,
+    last_flow_rate: Mutex<Option<FlowRate>>,
+    abr_controller: Mutex<Option<AdaptiveBitrateController>>,
+    tickets: TicketAuthority,
+    crypto_pool: Mutex<Option<CryptoWorkerPool>>,
+    liveness_monitor: Mutex<Option<tokio::task::AbortHandle>>,
+    next_reconnect_delay: Mutex<Option<Duration>>,
+    negotiated_version: Mutex<Option<u16>>,
+    /// Next sequence number `encode_frame` will stamp on an outgoing frame.
+    /// Deliberately outside `session_secrets`/`clear_secrets`: a byte stream
+    /// carried across a reconnect, or across `shutdown` and `resume`, still
+    /// needs its sequence numbers to keep climbing, not reset to 0.
+    frame_send_sequence: Mutex<u64>,
+    /// Sequence number `decode_ready_frames` requires of the next inbound
+    /// frame. Same reconnect-survives rationale as `frame_send_sequence`.
+    frame_recv_sequence: Mutex<u64>,
+    frame_reassembly: Mutex<std::collections::VecDeque<u8>>,
+    /// Next id `encrypt_payload` will hand to `pool::CryptoWorkerPool::encrypt`
+    /// so two chunked payloads sealed under the same session secret never
+    /// reuse a chunk's (key, nonce) pair. Same reconnect-survives rationale
+    /// as `frame_send_sequence`: harmless to keep climbing across a
+    /// reconnect or a fresh set of secrets, and simpler than resetting it in
+    /// lockstep with `clear_secrets`.
+    chunk_message_id: Mutex<u64>,
 }
 
 impl EngineState {
@@ -31,7 +84,20 @@ impl EngineState {
             state_machine: SessionStateMachine::new(),
             last_config: Mutex::new(None),
             last_heartbeat: Mutex::new(None),
+            rtt_estimator: Mutex::new(None),
             session_secrets: Mutex::new(None),
+            breakers: BreakerRegistry::new(),
+            last_flow_rate: Mutex::new(None),
+            abr_controller: Mutex::new(None),
+            tickets: TicketAuthority::new(),
+            crypto_pool: Mutex::new(None),
+            liveness_monitor: Mutex::new(None),
+            next_reconnect_delay: Mutex::new(None),
+            negotiated_version: Mutex::new(None),
+            frame_send_sequence: Mutex::new(0),
+            frame_recv_sequence: Mutex::new(0),
+            frame_reassembly: Mutex::new(std::collections::VecDeque::new()),
+            chunk_message_id: Mutex::new(0),
         }
     }
 
@@ -66,6 +132,33 @@ impl EngineState {
         Ok(())
     }
 
+    fn last_heartbeat(&self) -> Option<Instant> {
+        *self.last_heartbeat.lock().unwrap()
+    }
+
+    /// Resets the heartbeat clock without the rate-limit check
+    /// `record_heartbeat` applies, so the liveness monitor can mark a
+    /// successful reconnect as "alive" without racing the caller's own
+    /// heartbeat cadence.
+    fn touch_heartbeat(&self) {
+        *self.last_heartbeat.lock().unwrap() = Some(Instant::now());
+    }
+
+    /// Folds `sample` into the running [`RttEstimator`], seeding one from
+    /// this sample alone if no heartbeat ack has landed yet this session.
+    fn record_rtt_sample(&self, sample: Duration) {
+        let sample_ms = sample.as_secs_f64() * 1_000.0;
+        let mut estimator = self.rtt_estimator.lock().unwrap();
+        match estimator.as_mut() {
+            Some(estimator) => estimator.update(sample_ms),
+            None => *estimator = Some(RttEstimator::from_sample(sample_ms)),
+        }
+    }
+
+    fn rtt_estimator(&self) -> Option<RttEstimator> {
+        *self.rtt_estimator.lock().unwrap()
+    }
+
     fn store_secrets(&self, secrets: crypto::SessionSecrets) {
         *self.session_secrets.lock().unwrap() = Some(secrets);
     }
@@ -76,6 +169,53 @@ impl EngineState {
 
     fn clear_secrets(&self) {
         self.session_secrets.lock().unwrap().take();
+        self.negotiated_version.lock().unwrap().take();
+        self.rtt_estimator.lock().unwrap().take();
+    }
+
+    fn set_negotiated_version(&self, version: Option<u16>) {
+        *self.negotiated_version.lock().unwrap() = version;
+    }
+
+    fn negotiated_version(&self) -> Option<u16> {
+        *self.negotiated_version.lock().unwrap()
+    }
+
+    /// The active session's connection ID, independent of whatever endpoint
+    /// it's currently reachable at. `None` before a handshake completes.
+    fn connection_id(&self) -> Option<[u8; 16]> {
+        self.session_secrets
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|secrets| secrets.connection_id)
+    }
+
+    fn set_next_reconnect_delay(&self, delay: Option<Duration>) {
+        *self.next_reconnect_delay.lock().unwrap() = delay;
+    }
+
+    fn next_reconnect_delay(&self) -> Option<Duration> {
+        *self.next_reconnect_delay.lock().unwrap()
+    }
+
+    /// Hands out the next outgoing frame sequence number and advances the counter.
+    fn next_frame_send_sequence(&self) -> Result<u64, error::CoreError> {
+        let mut sequence = self.frame_send_sequence.lock().unwrap();
+        let current = *sequence;
+        *sequence = current.checked_add(1).ok_or(error::CoreError::CounterExhausted)?;
+        Ok(current)
+    }
+
+    /// Hands out the next chunk-message id and advances the counter; see
+    /// `chunk_message_id`.
+    fn next_chunk_message_id(&self) -> Result<u64, error::CoreError> {
+        let mut message_id = self.chunk_message_id.lock().unwrap();
+        let current = *message_id;
+        *message_id = current
+            .checked_add(1)
+            .ok_or(error::CoreError::CounterExhausted)?;
+        Ok(current)
     }
 }
 
@@ -85,6 +225,40 @@ impl Default for EngineState {
     }
 }
 
+/// Point-in-time snapshot returned by [`CoreEngine::snapshot`].
+#[derive(Debug, Clone, Copy)]
+pub struct EngineSnapshot {
+    pub state: SessionState,
+    /// Milliseconds since the last heartbeat, or `None` if none has landed yet.
+    pub last_heartbeat_elapsed_ms: Option<u64>,
+    pub has_secrets: bool,
+    pub negotiated_version: Option<u16>,
+    /// Smoothed heartbeat RTT, or `None` before any ack has landed this session.
+    pub srtt_ms: Option<f64>,
+    /// The deadline `check_liveness_auto` is currently evaluating against, or
+    /// `None` before a session has been established.
+    pub liveness_deadline_ms: Option<u64>,
+}
+
+/// Handle to a background liveness monitor spawned by
+/// [`CoreEngine::spawn_liveness_monitor`]. `CoreEngine::shutdown` already
+/// aborts the task on its own; keep this handle only if the caller wants to
+/// abort it earlier or check whether it's still running.
+pub struct LivenessMonitorHandle {
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl LivenessMonitorHandle {
+    /// Stops the monitor task. Safe to call more than once.
+    pub fn abort(&self) {
+        self.task.abort();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
 /// CoreEngine ties together session, streaming, and crypto primitives.
 pub struct CoreEngine<S, C, P, H>
 where
@@ -127,6 +301,32 @@ where
             return Err(error::CoreError::AlreadyInitialized);
         }
 
+        if config.min_supported > PROTOCOL_VERSION_MAX {
+            return Err(error::CoreError::UnsupportedProtocol {
+                requested_min: config.min_supported,
+                max_supported: PROTOCOL_VERSION_MAX,
+            });
+        }
+        if config.threshold_params.is_some()
+            && self.crypto.algorithm() != threshold::THRESHOLD_ALGORITHM_ID
+        {
+            return Err(error::CoreError::InvalidConfig {
+                reason: format!(
+                    "threshold_params requires a {} crypto provider, got {}",
+                    threshold::THRESHOLD_ALGORITHM_ID,
+                    self.crypto.algorithm()
+                ),
+            });
+        }
+        if config.peer_identity.is_some() && self.crypto.identity_public_key().is_none() {
+            return Err(error::CoreError::InvalidConfig {
+                reason: "peer_identity requires a crypto provider that supports \
+                    identity-bound handshakes"
+                    .into(),
+            });
+        }
+        let negotiated_version = config.protocol_version.min(PROTOCOL_VERSION_MAX);
+
         self.state.set_state(SessionState::Connecting)?;
 
         let config_snapshot = config.clone();
@@ -137,7 +337,24 @@ where
                 .as_deref()
                 .ok_or(error::CoreError::MissingCryptoMaterial)?;
             self.crypto.begin_handshake().await?;
-            let secrets = self.crypto.finalize_handshake(peer_key).await?;
+            let secrets = match (&config.peer_identity, &config.peer_suite_preference) {
+                (Some(peer_identity), _) => {
+                    self.crypto
+                        .finalize_handshake_authenticated(
+                            peer_key,
+                            &peer_identity.random,
+                            &peer_identity.public_key,
+                            &peer_identity.signature,
+                        )
+                        .await?
+                }
+                (None, Some(peer_suite_preference)) => {
+                    self.crypto
+                        .finalize_handshake_with_suite(peer_key, peer_suite_preference)
+                        .await?
+                }
+                (None, None) => self.crypto.finalize_handshake(peer_key).await?,
+            };
             self.state.store_secrets(secrets);
             self.session_manager.establish_async(config).await
         }
@@ -145,7 +362,16 @@ where
 
         match init_result {
             Ok(()) => {
+                if let Some(abr_config) = config_snapshot.abr_config {
+                    *self.state.abr_controller.lock().unwrap() =
+                        Some(AdaptiveBitrateController::new(abr_config, abr_config.floor_bps));
+                }
+                if let Some(pool_config) = config_snapshot.crypto_pool {
+                    *self.state.crypto_pool.lock().unwrap() =
+                        Some(CryptoWorkerPool::new(pool_config));
+                }
                 self.state.mark_config(config_snapshot);
+                self.state.set_negotiated_version(Some(negotiated_version));
                 self.state.set_state(SessionState::Connected)?;
                 Ok(())
             }
@@ -164,14 +390,74 @@ where
 
     /// Issues a stream flow control adjustment asynchronously.
     pub async fn throttle_stream(&self, rate: FlowRate) {
+        *self.state.last_flow_rate.lock().unwrap() = Some(rate);
         self.stream_controller.adjust_flow(rate).await;
     }
 
-    /// Attempts to reconnect an interrupted session.
+    /// Runs one AIMD step of the adaptive bitrate controller configured via
+    /// `SessionConfig::abr_config`, sampling `rtt_sampler` and pushing the
+    /// recomputed target through `adjust_flow`. Callers are expected to
+    /// invoke this on their own timer (see `stream::AbrConfig::interval`).
+    pub async fn adaptive_bitrate_step<R: RttSampler>(
+        &self,
+        rtt_sampler: &R,
+    ) -> Result<FlowRate, error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let max_latency_ms = self
+            .state
+            .last_flow_rate
+            .lock()
+            .unwrap()
+            .map(|rate| rate.max_latency_ms)
+            .unwrap_or(100);
+
+        if self.state.abr_controller.lock().unwrap().is_none() {
+            return Err(error::CoreError::InvalidConfig {
+                reason: "adaptive bitrate not configured for this session".into(),
+            });
+        }
+
+        let metrics = self.stream_controller.metrics().await;
+        let rtt = rtt_sampler.sample_rtt();
+        let new_rate = {
+            let guard = self.state.abr_controller.lock().unwrap();
+            let controller = guard.as_ref().expect("checked above");
+            controller.step(metrics, rtt, max_latency_ms)
+        };
+        self.throttle_stream(new_rate).await;
+        Ok(new_rate)
+    }
+
+    /// Attempts to reconnect an interrupted session using the last
+    /// established [`SessionConfig`]'s `reconnect_strategy`, or
+    /// [`ReconnectStrategy::default`] if none was configured.
     ///
     /// The operation awaits the underlying session reconnect and enforces state
     /// preconditions via the explicit state machine.
     pub async fn reconnect(&self) -> Result<(), error::CoreError> {
+        let strategy = self
+            .state
+            .last_config()
+            .and_then(|config| config.reconnect_strategy)
+            .unwrap_or_default();
+        self.reconnect_with(&strategy).await
+    }
+
+    /// Attempts to reconnect an interrupted session, retrying on failure per
+    /// `strategy` (exponential backoff with full jitter) while staying in
+    /// `SessionState::Reconnecting` across the whole sequence. Only falls
+    /// back to `Disconnected` once `strategy`'s attempts are exhausted,
+    /// returning [`CoreError::ReconnectExhausted`] with the attempt count,
+    /// elapsed backoff time, and the last underlying error.
+    pub async fn reconnect_with(
+        &self,
+        strategy: &ReconnectStrategy,
+    ) -> Result<(), error::CoreError> {
         let current = self.state.state();
         if current != SessionState::Connected {
             return Err(error::CoreError::InvalidState {
@@ -185,28 +471,150 @@ where
             .last_config()
             .ok_or(error::CoreError::MissingConfig)?;
 
+        self.state.breakers.should_try(&config.client_id)?;
         self.state.set_state(SessionState::Reconnecting)?;
 
-        let reconnect_result = self.session_manager.reconnect_async().await;
-        match reconnect_result {
-            Ok(()) => {
-                // ensure configuration persists for future heartbeats
-                self.state.mark_config(config);
-                self.state.set_state(SessionState::Connected)?;
-                Ok(())
-            }
-            Err(err) => {
-                let _ = self.state.set_state(SessionState::Disconnected);
-                Err(err)
+        let started_at = Instant::now();
+        let mut attempt = 0u32;
+        loop {
+            match self.session_manager.reconnect_async().await {
+                Ok(()) => {
+                    self.state.breakers.record_success(&config.client_id);
+                    self.state.set_next_reconnect_delay(None);
+                    // ensure configuration persists for future heartbeats
+                    self.state.mark_config(config);
+                    self.state.set_state(SessionState::Connected)?;
+                    return Ok(());
+                }
+                Err(err) => {
+                    self.state.breakers.record_failure(&config.client_id);
+                    if strategy.attempts_exhausted(attempt) {
+                        self.state.set_next_reconnect_delay(None);
+                        let _ = self.state.set_state(SessionState::Disconnected);
+                        return Err(error::CoreError::ReconnectExhausted {
+                            attempts: attempt + 1,
+                            elapsed_ms: started_at.elapsed().as_millis() as u64,
+                            last_error: Box::new(err),
+                        });
+                    }
+                    let delay = strategy.jittered_delay_for(attempt);
+                    self.state.set_next_reconnect_delay(Some(delay));
+                    tokio::time::sleep(delay).await;
+                    self.state.set_next_reconnect_delay(None);
+                    attempt += 1;
+                }
             }
         }
     }
 
+    /// The delay `reconnect_with` is currently sleeping through before its
+    /// next attempt, in milliseconds, so a host scheduler can avoid polling
+    /// `reconnect` early. `None` when no backoff sleep is in progress
+    /// (idle, mid-attempt, or between attempts' sleep and the next call).
+    pub fn next_reconnect_delay_ms(&self) -> Option<u64> {
+        self.state
+            .next_reconnect_delay()
+            .map(|delay| delay.as_millis() as u64)
+    }
+
+    /// The wire-protocol version negotiated with the peer during the last
+    /// successful `initialize`, or `None` before a handshake completes.
+    pub fn negotiated_version(&self) -> Option<u16> {
+        self.state.negotiated_version()
+    }
+
+    /// Checks the last heartbeat against `missed_interval_tolerance`
+    /// heartbeat intervals, without the jitter or background polling of
+    /// `spawn_liveness_monitor`. Intended for hosts that drive their own
+    /// event loop and want an on-demand liveness check instead.
+    pub fn check_liveness(&self, missed_interval_tolerance: u32) -> Result<(), error::CoreError> {
+        let config = self
+            .state
+            .last_config()
+            .ok_or(error::CoreError::MissingConfig)?;
+        let last_heartbeat = self
+            .state
+            .last_heartbeat()
+            .ok_or(error::CoreError::MissingConfig)?;
+        let tolerance =
+            Duration::from_millis(config.heartbeat_interval_ms) * missed_interval_tolerance;
+        let elapsed = last_heartbeat.elapsed();
+        if elapsed > tolerance {
+            return Err(error::CoreError::HeartbeatTimeout {
+                elapsed_ms: elapsed.as_millis() as u64,
+            });
+        }
+        Ok(())
+    }
+
+    /// The deadline `check_liveness_auto` evaluates the last heartbeat
+    /// against: `srtt + 4*rttvar` from the running [`RttEstimator`], clamped
+    /// to `config.adaptive_liveness`'s bounds, or
+    /// `heartbeat_interval_ms * fallback_multiplier` before any ack has
+    /// landed to seed an estimate.
+    fn liveness_deadline_ms(&self, config: &SessionConfig) -> u64 {
+        let adaptive = config.adaptive_liveness.unwrap_or_default();
+        match self.state.rtt_estimator() {
+            Some(estimator) => estimator.deadline_ms(&adaptive),
+            None => config
+                .heartbeat_interval_ms
+                .saturating_mul(adaptive.fallback_multiplier as u64),
+        }
+    }
+
+    /// Like [`Self::check_liveness`], but derives the tolerance window from
+    /// the measured heartbeat RTT instead of a fixed interval multiple. See
+    /// [`Self::liveness_deadline_ms`] for how the deadline is computed.
+    pub fn check_liveness_auto(&self) -> Result<(), error::CoreError> {
+        let config = self
+            .state
+            .last_config()
+            .ok_or(error::CoreError::MissingConfig)?;
+        let last_heartbeat = self
+            .state
+            .last_heartbeat()
+            .ok_or(error::CoreError::MissingConfig)?;
+
+        let deadline = Duration::from_millis(self.liveness_deadline_ms(&config));
+        let elapsed = last_heartbeat.elapsed();
+        if elapsed > deadline {
+            return Err(error::CoreError::HeartbeatTimeout {
+                elapsed_ms: elapsed.as_millis() as u64,
+            });
+        }
+        Ok(())
+    }
+
+    /// Point-in-time view of engine state for hosts that want a single call
+    /// instead of several separate accessor calls.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        let config = self.state.last_config();
+        let srtt_ms = self.state.rtt_estimator().map(|estimator| estimator.srtt_ms());
+        let liveness_deadline_ms = config
+            .as_ref()
+            .map(|config| self.liveness_deadline_ms(config));
+
+        EngineSnapshot {
+            state: self.state.state(),
+            last_heartbeat_elapsed_ms: self
+                .state
+                .last_heartbeat()
+                .map(|instant| instant.elapsed().as_millis() as u64),
+            has_secrets: self.state.secrets().is_some(),
+            negotiated_version: self.state.negotiated_version(),
+            srtt_ms,
+            liveness_deadline_ms,
+        }
+    }
+
     /// Terminates the active session.
     ///
     /// Awaiting this call guarantees the session manager has fully released
     /// resources before the engine returns to `Disconnected`.
     pub async fn shutdown(&self) -> Result<(), error::CoreError> {
+        if let Some(monitor) = self.state.liveness_monitor.lock().unwrap().take() {
+            monitor.abort();
+        }
         self.state.set_state(SessionState::ShuttingDown)?;
         self.session_manager.terminate_async().await;
         self.state.set_state(SessionState::Disconnected)?;
@@ -214,6 +622,89 @@ where
         Ok(())
     }
 
+    /// Seals the active session's negotiated secrets and config into an
+    /// opaque [`ResumptionTicket`], typically just before `shutdown`, so a
+    /// future `resume` can skip the handshake's Diffie-Hellman round trips.
+    pub fn issue_resumption_ticket(&self) -> Result<ResumptionTicket, error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let config = self
+            .state
+            .last_config()
+            .ok_or(error::CoreError::MissingConfig)?;
+        let secrets = self
+            .state
+            .secrets()
+            .ok_or(error::CoreError::MissingCryptoMaterial)?;
+        self.state.tickets.issue(&secrets, &config)
+    }
+
+    /// Restores a session directly from a previously issued
+    /// [`ResumptionTicket`], transitioning `Disconnected -> Connected`
+    /// without re-running the handshake. Rejects tickets whose counter was
+    /// already consumed or falls outside the sliding acceptance window.
+    pub async fn resume(&self, ticket: ResumptionTicket) -> Result<(), error::CoreError> {
+        let current = self.state.state();
+        if current != SessionState::Disconnected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Disconnected".to_string(),
+                actual: current,
+            });
+        }
+
+        let (config, secrets) = self.state.tickets.redeem(&ticket)?;
+        self.state.store_secrets(secrets);
+        self.state.mark_config(config);
+        self.state.set_state(SessionState::Connected)?;
+        Ok(())
+    }
+
+    /// Moves the active session to `new_endpoint` without renegotiating
+    /// crypto, so it survives a NAT rebinding or a Wi-Fi -> cellular handoff.
+    ///
+    /// Seals a random challenge under the session's existing secrets and
+    /// asks `new_endpoint` to echo it back via
+    /// [`session::AsyncSessionManager::probe_path`]; only once the response
+    /// decrypts to the same challenge is the session committed to the new
+    /// path via [`session::AsyncSessionManager::commit_path`]. A failed or
+    /// spoofed probe leaves the current path untouched and returns
+    /// [`error::CoreError::PathValidationFailed`].
+    pub async fn migrate_path(&self, new_endpoint: &str) -> Result<(), error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let secrets = self
+            .state
+            .secrets()
+            .ok_or(error::CoreError::MissingCryptoMaterial)?;
+        let connection_id = secrets.connection_id;
+
+        let mut challenge = [0u8; 16];
+        OsRng.fill_bytes(&mut challenge);
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce);
+        let sealed = crypto::aead_seal_with_nonce(&secrets, &nonce, &challenge)?;
+
+        let response = self
+            .session_manager
+            .probe_path(new_endpoint, connection_id, &sealed)
+            .await?;
+        let opened = crypto::aead_open_with_nonce(&secrets, &nonce, &response)
+            .map_err(|_| error::CoreError::PathValidationFailed)?;
+        if opened != challenge {
+            return Err(error::CoreError::PathValidationFailed);
+        }
+
+        self.session_manager.commit_path(new_endpoint).await
+    }
+
     /// Emits a heartbeat if the session is connected.
     ///
     /// Returns [`CoreError::RateLimited`] when called faster than the configured
@@ -232,10 +723,19 @@ where
             .last_config()
             .ok_or(error::CoreError::MissingConfig)?;
         self.state.record_heartbeat(config.heartbeat_interval_ms)?;
-        self.heartbeat_emitter.emit().await
+
+        let sent_at = Instant::now();
+        self.heartbeat_emitter.emit().await?;
+        self.state.record_rtt_sample(sent_at.elapsed());
+        Ok(())
     }
 
     /// Encrypts payloads using the negotiated session secrets.
+    /// Payloads at or above the configured pool's
+    /// `ChunkedCipherConfig::parallel_threshold` are split into chunks and
+    /// sealed in parallel by the [`pool::CryptoWorkerPool`] set up from
+    /// `SessionConfig::crypto_pool`; smaller ones, or sessions with no pool
+    /// configured, seal on the caller thread as a single AEAD frame.
     pub fn encrypt_payload(&self, plaintext: &[u8]) -> Result<Vec<u8>, error::CoreError> {
         if self.state.state() != SessionState::Connected {
             return Err(error::CoreError::InvalidState {
@@ -247,10 +747,29 @@ where
             .state
             .secrets()
             .ok_or(error::CoreError::MissingCryptoMaterial)?;
-        self.crypto.encrypt(&secrets, plaintext)
+
+        let guard = self.state.crypto_pool.lock().unwrap();
+        if let Some(pool) = guard
+            .as_ref()
+            .filter(|pool| plaintext.len() >= pool.config().parallel_threshold)
+        {
+            let message_id = self.state.next_chunk_message_id()?;
+            let mut framed = vec![PAYLOAD_FRAME_CHUNKED];
+            framed.extend(pool.encrypt(&secrets, plaintext, message_id)?);
+            return Ok(framed);
+        }
+        drop(guard);
+
+        let mut framed = vec![PAYLOAD_FRAME_SINGLE];
+        framed.extend(self.crypto.encrypt(&secrets, plaintext)?);
+        Ok(framed)
     }
 
-    /// Decrypts payloads using the negotiated session secrets.
+    /// Decrypts payloads using the negotiated session secrets, dispatching
+    /// chunked frames produced by `encrypt_payload` back through the
+    /// configured crypto worker pool (or a throwaway one sized from
+    /// `pool::ChunkedCipherConfig::default`, if this session never
+    /// configured one but the peer's did).
     pub fn decrypt_payload(&self, ciphertext: &[u8]) -> Result<Vec<u8>, error::CoreError> {
         if self.state.state() != SessionState::Connected {
             return Err(error::CoreError::InvalidState {
@@ -262,14 +781,264 @@ where
             .state
             .secrets()
             .ok_or(error::CoreError::MissingCryptoMaterial)?;
-        self.crypto.decrypt(&secrets, ciphertext)
+
+        let (tag, body) = ciphertext
+            .split_first()
+            .ok_or_else(|| error::CoreError::Decrypt("empty payload frame".into()))?;
+
+        match *tag {
+            PAYLOAD_FRAME_SINGLE => self.crypto.decrypt(&secrets, body),
+            PAYLOAD_FRAME_CHUNKED => {
+                let guard = self.state.crypto_pool.lock().unwrap();
+                match guard.as_ref() {
+                    Some(pool) => pool.decrypt(&secrets, body),
+                    None => CryptoWorkerPool::new(pool::ChunkedCipherConfig::default())
+                        .decrypt(&secrets, body),
+                }
+            }
+            _ => Err(error::CoreError::Decrypt(
+                "unrecognized payload frame tag".into(),
+            )),
+        }
+    }
+
+    /// Seals `plaintext` into one self-describing, sequence-numbered frame
+    /// suitable for a caller streaming raw bytes off a socket: a 4-byte
+    /// sealed-length prefix, then an AEAD-sealed header (declared length +
+    /// sequence number) and body. Pair with `feed_frames` on the peer side.
+    /// The sequence counter lives on `EngineState`, not the session
+    /// secrets, so it keeps climbing across a reconnect or a fresh set of secrets.
+    pub fn encode_frame(&self, plaintext: &[u8]) -> Result<Vec<u8>, error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let secrets = self
+            .state
+            .secrets()
+            .ok_or(error::CoreError::MissingCryptoMaterial)?;
+        let sequence = self.state.next_frame_send_sequence()?;
+        FrameCodec::new(&self.crypto, &secrets).encode_frame(sequence, plaintext)
+    }
+
+    /// Appends `bytes` to the engine's frame reassembly buffer and decrypts
+    /// every frame that's now complete, in order, rejecting the first
+    /// out-of-sequence or malformed frame it finds (any already-decoded
+    /// frames from earlier in the same call are still returned). Partial
+    /// frames remain buffered for a future call.
+    pub fn feed_frames(&self, bytes: &[u8]) -> Result<Vec<Vec<u8>>, error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let secrets = self
+            .state
+            .secrets()
+            .ok_or(error::CoreError::MissingCryptoMaterial)?;
+
+        let mut reassembly = self.state.frame_reassembly.lock().unwrap();
+        reassembly.extend(bytes);
+        let mut expected_sequence = self.state.frame_recv_sequence.lock().unwrap();
+        let codec = FrameCodec::new(&self.crypto, &secrets);
+        let decoded = codec.decode_ready_frames(&mut reassembly, &mut expected_sequence)?;
+        Ok(decoded)
+    }
+
+    /// Clears any buffered partial frame and resets this session's frame
+    /// sequence counters to 0 — not called automatically; a caller that
+    /// deliberately wants to resynchronize after rejecting an out-of-order
+    /// frame (rather than tearing down the session) can opt into it.
+    pub fn reset_frame_sequencing(&self) {
+        self.state.frame_reassembly.lock().unwrap().clear();
+        *self.state.frame_send_sequence.lock().unwrap() = 0;
+        *self.state.frame_recv_sequence.lock().unwrap() = 0;
+    }
+
+    /// Seals `plaintext` behind `record::RecordLayer`'s padding ladder and
+    /// writes it to `writer`. The record-layer alternative to `encode_frame`,
+    /// for embedders holding a real `AsyncWrite` socket instead of exchanging
+    /// raw byte buffers across the C FFI boundary (there's no way to pass an
+    /// async reader/writer across that ABI, so this pair isn't exposed
+    /// through `ffi`). Pair with `read_record` on the peer side.
+    pub async fn write_record<W: AsyncWrite + Unpin>(
+        &self,
+        writer: &mut W,
+        plaintext: &[u8],
+    ) -> Result<(), error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let secrets = self
+            .state
+            .secrets()
+            .ok_or(error::CoreError::MissingCryptoMaterial)?;
+        RecordLayer::new(&self.crypto, &secrets)
+            .write_record(writer, plaintext)
+            .await
+    }
+
+    /// Inverse of `write_record`: reads one length-hidden record written by
+    /// the peer's `write_record` off `reader` and returns its decrypted
+    /// plaintext.
+    pub async fn read_record<R: AsyncRead + Unpin>(
+        &self,
+        reader: &mut R,
+    ) -> Result<Vec<u8>, error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let secrets = self
+            .state
+            .secrets()
+            .ok_or(error::CoreError::MissingCryptoMaterial)?;
+        RecordLayer::new(&self.crypto, &secrets).read_record(reader).await
+    }
+
+    /// Starts a key rotation, returning a `PAYLOAD_FRAME_ROTATION` frame
+    /// whose body is a fresh ephemeral public key for the peer to feed into
+    /// its own `complete_rotation`. Sending the frame and driving the
+    /// peer's matching rotation is left to the caller; this only prepares
+    /// the provider's side of the exchange via
+    /// `SessionCryptoProvider::rotate_now`.
+    pub async fn begin_rotation(&self) -> Result<Vec<u8>, error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let mut framed = vec![PAYLOAD_FRAME_ROTATION];
+        framed.extend(self.crypto.rotate_now().await?);
+        Ok(framed)
+    }
+
+    /// Completes a key rotation using `frame`, a `PAYLOAD_FRAME_ROTATION`
+    /// frame received from the peer (either the peer's own `begin_rotation`
+    /// output, or the answer to this side's). Re-derives the shared secret
+    /// against the enclosed ephemeral key and ratchets the session's AEAD
+    /// keys forward, resetting the directional counters.
+    pub async fn complete_rotation(&self, frame: &[u8]) -> Result<(), error::CoreError> {
+        if self.state.state() != SessionState::Connected {
+            return Err(error::CoreError::InvalidState {
+                expected: "Connected".to_string(),
+                actual: self.state.state(),
+            });
+        }
+        let secrets = self
+            .state
+            .secrets()
+            .ok_or(error::CoreError::MissingCryptoMaterial)?;
+        let (tag, peer_rotation_public_key) = frame
+            .split_first()
+            .ok_or_else(|| error::CoreError::Decrypt("empty payload frame".into()))?;
+        if *tag != PAYLOAD_FRAME_ROTATION {
+            return Err(error::CoreError::Decrypt(
+                "unrecognized payload frame tag".into(),
+            ));
+        }
+        self.crypto
+            .complete_rotation(&secrets, peer_rotation_public_key)
+            .await
+    }
+
+    /// Reports whether the current session secrets have crossed
+    /// `threshold`'s message or byte count and should be rotated via
+    /// `begin_rotation`. `false` before a handshake has completed.
+    pub fn rotation_due(&self, threshold: &crypto::RotationThreshold) -> bool {
+        self.state
+            .secrets()
+            .map(|secrets| secrets.needs_rotation(threshold))
+            .unwrap_or(false)
+    }
+}
+
+impl<S, C, P, H> CoreEngine<S, C, P, H>
+where
+    S: AsyncSessionManager + 'static,
+    C: StreamController + 'static,
+    P: SessionCryptoProvider + 'static,
+    H: HeartbeatEmitter + 'static,
+{
+    /// Spawns a background task watching `last_heartbeat`; once the gap
+    /// exceeds `config`'s tolerance window it reports a [`LivenessFailure`]
+    /// to `on_failure` and retries the session via `reconnect_with` with the
+    /// default [`ReconnectStrategy`]. Call this after `initialize` succeeds.
+    ///
+    /// The task is spawned with `tokio::task::spawn_local` rather than
+    /// `tokio::spawn`, since the session/crypto traits above are `?Send`;
+    /// the caller's runtime must be driving a `tokio::task::LocalSet` for it
+    /// to run. `CoreEngine::shutdown` aborts it automatically, but callers
+    /// needing to stop it sooner can use the returned handle.
+    pub fn spawn_liveness_monitor(
+        self: &Arc<Self>,
+        config: LivenessConfig,
+        on_failure: impl Fn(LivenessFailure) + 'static,
+    ) -> LivenessMonitorHandle {
+        let engine = Arc::clone(self);
+        let task = tokio::task::spawn_local(async move {
+            engine.run_liveness_monitor(config, on_failure).await
+        });
+        *self.state.liveness_monitor.lock().unwrap() = Some(task.abort_handle());
+        LivenessMonitorHandle { task }
+    }
+
+    async fn run_liveness_monitor(
+        &self,
+        config: LivenessConfig,
+        on_failure: impl Fn(LivenessFailure),
+    ) {
+        loop {
+            tokio::time::sleep(config.poll_interval).await;
+
+            if self.state.state() != SessionState::Connected {
+                continue;
+            }
+            let Some(heartbeat_interval_ms) = self
+                .state
+                .last_config()
+                .map(|config| config.heartbeat_interval_ms)
+            else {
+                continue;
+            };
+            let Some(last_heartbeat) = self.state.last_heartbeat() else {
+                continue;
+            };
+
+            let tolerance =
+                config.tolerance_window(heartbeat_interval_ms) + config.jittered_grace();
+            let elapsed = last_heartbeat.elapsed();
+            if elapsed < tolerance {
+                continue;
+            }
+
+            on_failure(LivenessFailure {
+                missed_intervals: config.missed_interval_tolerance,
+                elapsed,
+            });
+
+            if self.reconnect_with(&ReconnectStrategy::default()).await.is_ok() {
+                self.state.touch_heartbeat();
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::crypto::{KeyExchangeProvider, P256KeyExchange, P256SessionCrypto, SessionSecrets};
+    use crate::crypto::{
+        AeadSuiteId, KeyExchangeProvider, P256KeyExchange, P256SessionCrypto, SessionSecrets,
+    };
     use crate::session::{SessionConfig, SessionState};
     use crate::stream::FlowRate;
     use std::sync::{Arc, Mutex};
@@ -399,26 +1168,152 @@ mod tests {
         }
     }
 
-    struct DummyHeartbeatEmitter {
+    /// Like `DummyCrypto`, but declares identity support and records when
+    /// `initialize` reaches for `finalize_handshake_authenticated` instead of
+    /// plain `finalize_handshake`, so `peer_identity` wiring can be exercised
+    /// without reproducing `crypto.rs`'s own signature-verification tests.
+    struct IdentityCrypto {
         recorder: Recorder,
+        inner: P256SessionCrypto<P256KeyExchange>,
     }
 
     #[async_trait::async_trait(?Send)]
-    impl HeartbeatEmitter for DummyHeartbeatEmitter {
-        async fn emit(&self) -> Result<(), error::CoreError> {
-            self.recorder.push("heartbeat_emit");
-            Ok(())
+    impl SessionCryptoProvider for IdentityCrypto {
+        async fn validate_device_identity(&self) -> Result<(), error::CoreError> {
+            self.inner.validate_device_identity().await
         }
-    }
 
-    fn build_engine(
-        recorder: Recorder,
-    ) -> CoreEngine<DummySessionManager, DummyStreamController, DummyCrypto, DummyHeartbeatEmitter>
-    {
-        CoreEngine::new(
-            DummySessionManager::new(recorder.clone()),
-            DummyStreamController {
-                recorder: recorder.clone(),
+        async fn begin_handshake(&self) -> Result<Vec<u8>, error::CoreError> {
+            self.inner.begin_handshake().await
+        }
+
+        async fn finalize_handshake(
+            &self,
+            peer_public_key: &[u8],
+        ) -> Result<SessionSecrets, error::CoreError> {
+            self.inner.finalize_handshake(peer_public_key).await
+        }
+
+        fn local_public_key(&self) -> Option<Vec<u8>> {
+            self.inner.local_public_key()
+        }
+
+        fn algorithm(&self) -> &'static str {
+            self.inner.algorithm()
+        }
+
+        fn encrypt(
+            &self,
+            secrets: &SessionSecrets,
+            plaintext: &[u8],
+        ) -> Result<Vec<u8>, error::CoreError> {
+            self.inner.encrypt(secrets, plaintext)
+        }
+
+        fn decrypt(
+            &self,
+            secrets: &SessionSecrets,
+            ciphertext: &[u8],
+        ) -> Result<Vec<u8>, error::CoreError> {
+            self.inner.decrypt(secrets, ciphertext)
+        }
+
+        fn identity_public_key(&self) -> Option<[u8; 32]> {
+            Some([0u8; 32])
+        }
+
+        async fn finalize_handshake_authenticated(
+            &self,
+            peer_public_key: &[u8],
+            _peer_random: &[u8; 64],
+            _peer_identity_public_key: &[u8; 32],
+            _peer_signature: &[u8; 64],
+        ) -> Result<SessionSecrets, error::CoreError> {
+            self.recorder.push("crypto_finalize_authenticated");
+            self.inner.finalize_handshake(peer_public_key).await
+        }
+    }
+
+    /// Like `DummyCrypto`, but records when `initialize` reaches for
+    /// `finalize_handshake_with_suite` instead of plain `finalize_handshake`,
+    /// so `peer_suite_preference` wiring can be exercised without
+    /// reproducing `crypto.rs`'s own suite-negotiation tests.
+    struct SuiteCrypto {
+        recorder: Recorder,
+        inner: P256SessionCrypto<P256KeyExchange>,
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl SessionCryptoProvider for SuiteCrypto {
+        async fn validate_device_identity(&self) -> Result<(), error::CoreError> {
+            self.inner.validate_device_identity().await
+        }
+
+        async fn begin_handshake(&self) -> Result<Vec<u8>, error::CoreError> {
+            self.inner.begin_handshake().await
+        }
+
+        async fn finalize_handshake(
+            &self,
+            peer_public_key: &[u8],
+        ) -> Result<SessionSecrets, error::CoreError> {
+            self.inner.finalize_handshake(peer_public_key).await
+        }
+
+        fn local_public_key(&self) -> Option<Vec<u8>> {
+            self.inner.local_public_key()
+        }
+
+        fn algorithm(&self) -> &'static str {
+            self.inner.algorithm()
+        }
+
+        fn encrypt(
+            &self,
+            secrets: &SessionSecrets,
+            plaintext: &[u8],
+        ) -> Result<Vec<u8>, error::CoreError> {
+            self.inner.encrypt(secrets, plaintext)
+        }
+
+        fn decrypt(
+            &self,
+            secrets: &SessionSecrets,
+            ciphertext: &[u8],
+        ) -> Result<Vec<u8>, error::CoreError> {
+            self.inner.decrypt(secrets, ciphertext)
+        }
+
+        async fn finalize_handshake_with_suite(
+            &self,
+            peer_public_key: &[u8],
+            _peer_suite_preference: &[AeadSuiteId],
+        ) -> Result<SessionSecrets, error::CoreError> {
+            self.recorder.push("crypto_finalize_with_suite");
+            self.inner.finalize_handshake(peer_public_key).await
+        }
+    }
+
+    struct DummyHeartbeatEmitter {
+        recorder: Recorder,
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl HeartbeatEmitter for DummyHeartbeatEmitter {
+        async fn emit(&self) -> Result<(), error::CoreError> {
+            self.recorder.push("heartbeat_emit");
+            Ok(())
+        }
+    }
+
+    fn build_engine(
+        recorder: Recorder,
+    ) -> CoreEngine<DummySessionManager, DummyStreamController, DummyCrypto, DummyHeartbeatEmitter>
+    {
+        CoreEngine::new(
+            DummySessionManager::new(recorder.clone()),
+            DummyStreamController {
+                recorder: recorder.clone(),
             },
             DummyCrypto {
                 recorder: recorder.clone(),
@@ -445,6 +1340,15 @@ mod tests {
             client_id: "demo".into(),
             heartbeat_interval_ms: 1_000,
             peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
 
         assert!(engine.initialize(config).await.is_ok());
@@ -471,6 +1375,15 @@ mod tests {
             client_id: "demo".into(),
             heartbeat_interval_ms: 1_000,
             peer_public_key: None,
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
 
         let err = engine.initialize(config).await.unwrap_err();
@@ -478,6 +1391,116 @@ mod tests {
         assert_eq!(engine.state.state(), SessionState::Disconnected);
     }
 
+    #[tokio::test]
+    async fn initialize_rejects_peer_identity_when_crypto_does_not_support_it() {
+        let recorder = Recorder::new();
+        let engine = build_engine(recorder);
+
+        let config = SessionConfig {
+            client_id: "demo".into(),
+            heartbeat_interval_ms: 1_000,
+            peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: Some(session::PeerIdentity {
+                public_key: [0u8; 32],
+                random: [0u8; 64],
+                signature: [0u8; 64],
+            }),
+            peer_suite_preference: None,
+        };
+
+        let err = engine.initialize(config).await.unwrap_err();
+        assert!(matches!(err, error::CoreError::InvalidConfig { .. }));
+        assert_eq!(engine.state.state(), SessionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn initialize_authenticates_handshake_when_peer_identity_configured() {
+        let recorder = Recorder::new();
+        let engine = CoreEngine::new(
+            DummySessionManager::new(recorder.clone()),
+            DummyStreamController {
+                recorder: recorder.clone(),
+            },
+            IdentityCrypto {
+                recorder: recorder.clone(),
+                inner: P256SessionCrypto::new(P256KeyExchange),
+            },
+            DummyHeartbeatEmitter {
+                recorder: recorder.clone(),
+            },
+        );
+
+        let config = SessionConfig {
+            client_id: "demo".into(),
+            heartbeat_interval_ms: 1_000,
+            peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: Some(session::PeerIdentity {
+                public_key: [0u8; 32],
+                random: [0u8; 64],
+                signature: [0u8; 64],
+            }),
+            peer_suite_preference: None,
+        };
+
+        engine.initialize(config).await.unwrap();
+        assert_eq!(engine.state.state(), SessionState::Connected);
+        assert!(recorder.entries().contains(&"crypto_finalize_authenticated"));
+    }
+
+    #[tokio::test]
+    async fn initialize_negotiates_suite_when_peer_suite_preference_configured() {
+        let recorder = Recorder::new();
+        let engine = CoreEngine::new(
+            DummySessionManager::new(recorder.clone()),
+            DummyStreamController {
+                recorder: recorder.clone(),
+            },
+            SuiteCrypto {
+                recorder: recorder.clone(),
+                inner: P256SessionCrypto::new(P256KeyExchange),
+            },
+            DummyHeartbeatEmitter {
+                recorder: recorder.clone(),
+            },
+        );
+
+        let config = SessionConfig {
+            client_id: "demo".into(),
+            heartbeat_interval_ms: 1_000,
+            peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: Some(vec![
+                AeadSuiteId::ChaCha20Poly1305,
+                AeadSuiteId::Aes256Gcm,
+            ]),
+        };
+
+        engine.initialize(config).await.unwrap();
+        assert_eq!(engine.state.state(), SessionState::Connected);
+        assert!(recorder.entries().contains(&"crypto_finalize_with_suite"));
+    }
+
     #[tokio::test]
     async fn heartbeats_require_connected_state() {
         let recorder = Recorder::new();
@@ -498,6 +1521,15 @@ mod tests {
             client_id: "demo".into(),
             heartbeat_interval_ms: 1_000,
             peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
         engine.initialize(config).await.unwrap();
         engine.send_heartbeat().await.unwrap();
@@ -506,6 +1538,48 @@ mod tests {
         assert_eq!(entries.last(), Some(&"heartbeat_emit"));
     }
 
+    #[tokio::test]
+    async fn check_liveness_auto_falls_back_before_any_rtt_sample() {
+        let recorder = Recorder::new();
+        let engine = build_engine(recorder);
+
+        let config = SessionConfig {
+            client_id: "demo".into(),
+            heartbeat_interval_ms: 10,
+            peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
+        };
+        engine.initialize(config).await.unwrap();
+
+        // No heartbeat ack has landed yet, so the deadline falls back to
+        // heartbeat_interval_ms * AdaptiveLivenessConfig::default().fallback_multiplier (3),
+        // not a live RTT estimate.
+        assert!(engine.state.rtt_estimator().is_none());
+        let snapshot = engine.snapshot();
+        assert_eq!(snapshot.srtt_ms, None);
+        assert_eq!(snapshot.liveness_deadline_ms, Some(30));
+        engine.check_liveness_auto().unwrap();
+
+        engine.send_heartbeat().await.unwrap();
+        assert!(engine.state.rtt_estimator().is_some());
+        let snapshot = engine.snapshot();
+        assert!(snapshot.srtt_ms.is_some());
+
+        // One near-zero RTT sample puts the deadline at the default
+        // 1_000 ms `min_deadline_ms` floor.
+        sleep(Duration::from_millis(1_050)).await;
+        let err = engine.check_liveness_auto().unwrap_err();
+        assert!(matches!(err, error::CoreError::HeartbeatTimeout { .. }));
+    }
+
     #[tokio::test]
     async fn flow_control_and_reconnect_are_routed_and_stateful() {
         let recorder = Recorder::new();
@@ -523,6 +1597,15 @@ mod tests {
                 client_id: "demo".into(),
                 heartbeat_interval_ms: 1_000,
                 peer_public_key: Some(sample_peer_key().await),
+                abr_config: None,
+                crypto_pool: None,
+                reconnect_strategy: None,
+                protocol_version: 1,
+                min_supported: 1,
+                adaptive_liveness: None,
+                threshold_params: None,
+                peer_identity: None,
+                peer_suite_preference: None,
             })
             .await
             .unwrap();
@@ -557,6 +1640,15 @@ mod tests {
             client_id: "demo".into(),
             heartbeat_interval_ms: 1_000,
             peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
 
         engine.initialize(config.clone()).await.unwrap();
@@ -573,6 +1665,15 @@ mod tests {
             client_id: "demo".into(),
             heartbeat_interval_ms: 50,
             peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
 
         engine.initialize(config).await.unwrap();
@@ -588,6 +1689,262 @@ mod tests {
         assert!(entries.iter().filter(|e| **e == "heartbeat_emit").count() >= 2);
     }
 
+    #[tokio::test]
+    async fn reconnect_with_retries_then_exhausts_with_diagnostics() {
+        struct FlakySessionManager {
+            state: Arc<Mutex<SessionState>>,
+            remaining_failures: Mutex<u32>,
+        }
+
+        #[async_trait::async_trait(?Send)]
+        impl AsyncSessionManager for FlakySessionManager {
+            async fn establish_async(&self, _config: SessionConfig) -> Result<(), error::CoreError> {
+                *self.state.lock().unwrap() = SessionState::Connected;
+                Ok(())
+            }
+
+            async fn reconnect_async(&self) -> Result<(), error::CoreError> {
+                let mut remaining = self.remaining_failures.lock().unwrap();
+                if *remaining > 0 {
+                    *remaining -= 1;
+                    return Err(error::CoreError::Session("peer unreachable".into()));
+                }
+                Ok(())
+            }
+
+            async fn terminate_async(&self) {
+                *self.state.lock().unwrap() = SessionState::Disconnected;
+            }
+
+            fn state(&self) -> SessionState {
+                *self.state.lock().unwrap()
+            }
+        }
+
+        let recorder = Recorder::new();
+        let engine = CoreEngine::new(
+            FlakySessionManager {
+                state: Arc::new(Mutex::new(SessionState::Disconnected)),
+                remaining_failures: Mutex::new(2),
+            },
+            DummyStreamController {
+                recorder: recorder.clone(),
+            },
+            DummyCrypto {
+                recorder: recorder.clone(),
+                inner: P256SessionCrypto::new(P256KeyExchange),
+            },
+            DummyHeartbeatEmitter { recorder },
+        );
+
+        engine
+            .initialize(SessionConfig {
+                client_id: "flaky".into(),
+                heartbeat_interval_ms: 1_000,
+                peer_public_key: Some(sample_peer_key().await),
+                abr_config: None,
+                crypto_pool: None,
+                reconnect_strategy: None,
+                protocol_version: 1,
+                min_supported: 1,
+                adaptive_liveness: None,
+                threshold_params: None,
+                peer_identity: None,
+                peer_suite_preference: None,
+            })
+            .await
+            .unwrap();
+
+        let strategy = session::ReconnectStrategy {
+            initial_delay: Duration::from_millis(1),
+            multiplier: 1.0,
+            max_delay: Duration::from_millis(5),
+            max_attempts: Some(5),
+        };
+        engine.reconnect_with(&strategy).await.unwrap();
+        assert_eq!(engine.state.state(), SessionState::Connected);
+
+        *engine.session_manager.remaining_failures.lock().unwrap() = 100;
+        let exhausting = session::ReconnectStrategy {
+            max_attempts: Some(2),
+            ..strategy
+        };
+        let err = engine.reconnect_with(&exhausting).await.unwrap_err();
+        match err {
+            error::CoreError::ReconnectExhausted {
+                attempts,
+                last_error,
+                ..
+            } => {
+                assert_eq!(attempts, 3);
+                assert!(matches!(*last_error, error::CoreError::Session(_)));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(engine.state.state(), SessionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn reconnect_uses_configured_strategy_and_exposes_next_delay() {
+        struct FlakySessionManager {
+            state: Arc<Mutex<SessionState>>,
+            remaining_failures: Mutex<u32>,
+        }
+
+        #[async_trait::async_trait(?Send)]
+        impl AsyncSessionManager for FlakySessionManager {
+            async fn establish_async(&self, _config: SessionConfig) -> Result<(), error::CoreError> {
+                *self.state.lock().unwrap() = SessionState::Connected;
+                Ok(())
+            }
+
+            async fn reconnect_async(&self) -> Result<(), error::CoreError> {
+                let mut remaining = self.remaining_failures.lock().unwrap();
+                if *remaining > 0 {
+                    *remaining -= 1;
+                    return Err(error::CoreError::Session("peer unreachable".into()));
+                }
+                Ok(())
+            }
+
+            async fn terminate_async(&self) {
+                *self.state.lock().unwrap() = SessionState::Disconnected;
+            }
+
+            fn state(&self) -> SessionState {
+                *self.state.lock().unwrap()
+            }
+        }
+
+        let recorder = Recorder::new();
+        let engine = CoreEngine::new(
+            FlakySessionManager {
+                state: Arc::new(Mutex::new(SessionState::Disconnected)),
+                remaining_failures: Mutex::new(1),
+            },
+            DummyStreamController {
+                recorder: recorder.clone(),
+            },
+            DummyCrypto {
+                recorder: recorder.clone(),
+                inner: P256SessionCrypto::new(P256KeyExchange),
+            },
+            DummyHeartbeatEmitter { recorder },
+        );
+
+        assert_eq!(engine.next_reconnect_delay_ms(), None);
+
+        engine
+            .initialize(SessionConfig {
+                client_id: "flaky".into(),
+                heartbeat_interval_ms: 1_000,
+                peer_public_key: Some(sample_peer_key().await),
+                abr_config: None,
+                crypto_pool: None,
+                reconnect_strategy: Some(session::ReconnectStrategy {
+                    initial_delay: Duration::from_millis(1),
+                    multiplier: 1.0,
+                    max_delay: Duration::from_millis(5),
+                    max_attempts: Some(1),
+                }),
+                protocol_version: 1,
+                min_supported: 1,
+                adaptive_liveness: None,
+                threshold_params: None,
+                peer_identity: None,
+                peer_suite_preference: None,
+            })
+            .await
+            .unwrap();
+
+        engine.reconnect().await.unwrap();
+        assert_eq!(engine.state.state(), SessionState::Connected);
+        assert_eq!(engine.next_reconnect_delay_ms(), None);
+
+        *engine.session_manager.remaining_failures.lock().unwrap() = 100;
+        let err = engine.reconnect().await.unwrap_err();
+        assert!(matches!(err, error::CoreError::ReconnectExhausted { attempts: 2, .. }));
+        assert_eq!(engine.state.state(), SessionState::Disconnected);
+        assert_eq!(engine.next_reconnect_delay_ms(), None);
+    }
+
+    #[tokio::test]
+    async fn resumption_ticket_restores_session_without_handshake() {
+        let recorder = Recorder::new();
+        let engine = build_engine(recorder.clone());
+
+        engine
+            .initialize(SessionConfig {
+                client_id: "demo".into(),
+                heartbeat_interval_ms: 1_000,
+                peer_public_key: Some(sample_peer_key().await),
+                abr_config: None,
+                crypto_pool: None,
+                reconnect_strategy: None,
+                protocol_version: 1,
+                min_supported: 1,
+                adaptive_liveness: None,
+                threshold_params: None,
+                peer_identity: None,
+                peer_suite_preference: None,
+            })
+            .await
+            .unwrap();
+
+        let ticket = engine.issue_resumption_ticket().unwrap();
+        engine.shutdown().await.unwrap();
+        assert_eq!(engine.state.state(), SessionState::Disconnected);
+
+        // Resume skips the crypto handshake entirely.
+        let crypto_calls_before = recorder
+            .entries()
+            .iter()
+            .filter(|e| e.starts_with("crypto_"))
+            .count();
+        engine.resume(ticket).await.unwrap();
+        assert_eq!(engine.state.state(), SessionState::Connected);
+        let crypto_calls_after = recorder
+            .entries()
+            .iter()
+            .filter(|e| e.starts_with("crypto_"))
+            .count();
+        assert_eq!(crypto_calls_before, crypto_calls_after);
+
+        engine.encrypt_payload(b"resumed").unwrap();
+    }
+
+    #[tokio::test]
+    async fn resume_rejects_replayed_ticket() {
+        let recorder = Recorder::new();
+        let engine = build_engine(recorder);
+
+        engine
+            .initialize(SessionConfig {
+                client_id: "demo".into(),
+                heartbeat_interval_ms: 1_000,
+                peer_public_key: Some(sample_peer_key().await),
+                abr_config: None,
+                crypto_pool: None,
+                reconnect_strategy: None,
+                protocol_version: 1,
+                min_supported: 1,
+                adaptive_liveness: None,
+                threshold_params: None,
+                peer_identity: None,
+                peer_suite_preference: None,
+            })
+            .await
+            .unwrap();
+
+        let ticket = engine.issue_resumption_ticket().unwrap();
+        engine.shutdown().await.unwrap();
+        engine.resume(ticket.clone()).await.unwrap();
+        engine.shutdown().await.unwrap();
+
+        let err = engine.resume(ticket).await.unwrap_err();
+        assert!(matches!(err, error::CoreError::ResumptionRejected { .. }));
+    }
+
     #[tokio::test]
     async fn reconnect_requires_connected_state() {
         let recorder = Recorder::new();
@@ -623,6 +1980,15 @@ mod tests {
             client_id: "demo".into(),
             heartbeat_interval_ms: 1_000,
             peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
 
         engine.initialize(config).await.unwrap();
@@ -632,4 +1998,291 @@ mod tests {
         let roundtrip = engine.decrypt_payload(&ciphertext).unwrap();
         assert_eq!(roundtrip, b"payload");
     }
+
+    #[tokio::test]
+    async fn adaptive_bitrate_step_requires_config_and_connection() {
+        let recorder = Recorder::new();
+        let engine = build_engine(recorder);
+
+        let err = engine
+            .adaptive_bitrate_step(&crate::stream::NullRttSampler)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, error::CoreError::InvalidState { .. }));
+
+        let config = SessionConfig {
+            client_id: "demo".into(),
+            heartbeat_interval_ms: 1_000,
+            peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
+        };
+        engine.initialize(config).await.unwrap();
+
+        let err = engine
+            .adaptive_bitrate_step(&crate::stream::NullRttSampler)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, error::CoreError::InvalidConfig { .. }));
+    }
+
+    #[tokio::test]
+    async fn adaptive_bitrate_step_adjusts_flow_when_configured() {
+        let recorder = Recorder::new();
+        let engine = build_engine(recorder.clone());
+
+        let config = SessionConfig {
+            client_id: "demo".into(),
+            heartbeat_interval_ms: 1_000,
+            peer_public_key: Some(sample_peer_key().await),
+            abr_config: Some(crate::stream::AbrConfig::default()),
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
+        };
+        engine.initialize(config).await.unwrap();
+
+        let rate = engine
+            .adaptive_bitrate_step(&crate::stream::NullRttSampler)
+            .await
+            .unwrap();
+        assert!(rate.target_bitrate_bps >= crate::stream::AbrConfig::default().floor_bps);
+        assert_eq!(recorder.entries().last(), Some(&"stream_adjust"));
+    }
+
+    #[tokio::test]
+    async fn liveness_monitor_detects_stalled_heartbeat_and_reconnects() {
+        let recorder = Recorder::new();
+        let engine = Arc::new(build_engine(recorder.clone()));
+
+        let config = SessionConfig {
+            client_id: "demo".into(),
+            heartbeat_interval_ms: 10,
+            peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
+        };
+        engine.initialize(config).await.unwrap();
+        engine.send_heartbeat().await.unwrap();
+
+        let failures: Arc<Mutex<Vec<LivenessFailure>>> = Arc::new(Mutex::new(Vec::new()));
+        let failures_handle = failures.clone();
+
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let monitor = engine.spawn_liveness_monitor(
+                    LivenessConfig {
+                        missed_interval_tolerance: 2,
+                        poll_interval: Duration::from_millis(5),
+                        max_grace_jitter: Duration::from_millis(1),
+                    },
+                    move |failure| failures_handle.lock().unwrap().push(failure),
+                );
+
+                sleep(Duration::from_millis(100)).await;
+                monitor.abort();
+            })
+            .await;
+
+        assert!(!failures.lock().unwrap().is_empty());
+        assert!(recorder.entries().iter().any(|e| *e == "session_reconnect"));
+    }
+
+    #[tokio::test]
+    async fn migrate_path_commits_once_challenge_echoes_back() {
+        struct PathProbeSessionManager {
+            state: Arc<Mutex<SessionState>>,
+            committed_endpoint: Mutex<Option<String>>,
+            tamper_response: bool,
+        }
+
+        #[async_trait::async_trait(?Send)]
+        impl AsyncSessionManager for PathProbeSessionManager {
+            async fn establish_async(&self, _config: SessionConfig) -> Result<(), error::CoreError> {
+                *self.state.lock().unwrap() = SessionState::Connected;
+                Ok(())
+            }
+
+            async fn reconnect_async(&self) -> Result<(), error::CoreError> {
+                Ok(())
+            }
+
+            async fn terminate_async(&self) {
+                *self.state.lock().unwrap() = SessionState::Disconnected;
+            }
+
+            fn state(&self) -> SessionState {
+                *self.state.lock().unwrap()
+            }
+
+            async fn probe_path(
+                &self,
+                _new_endpoint: &str,
+                _connection_id: [u8; 16],
+                challenge: &[u8],
+            ) -> Result<Vec<u8>, error::CoreError> {
+                if self.tamper_response {
+                    return Ok(vec![0u8; challenge.len()]);
+                }
+                Ok(challenge.to_vec())
+            }
+
+            async fn commit_path(&self, new_endpoint: &str) -> Result<(), error::CoreError> {
+                *self.committed_endpoint.lock().unwrap() = Some(new_endpoint.to_string());
+                Ok(())
+            }
+        }
+
+        let recorder = Recorder::new();
+        let engine = CoreEngine::new(
+            PathProbeSessionManager {
+                state: Arc::new(Mutex::new(SessionState::Disconnected)),
+                committed_endpoint: Mutex::new(None),
+                tamper_response: false,
+            },
+            DummyStreamController {
+                recorder: recorder.clone(),
+            },
+            DummyCrypto {
+                recorder: recorder.clone(),
+                inner: P256SessionCrypto::new(P256KeyExchange),
+            },
+            DummyHeartbeatEmitter { recorder },
+        );
+
+        engine
+            .initialize(SessionConfig {
+                client_id: "roaming".into(),
+                heartbeat_interval_ms: 1_000,
+                peer_public_key: Some(sample_peer_key().await),
+                abr_config: None,
+                crypto_pool: None,
+                reconnect_strategy: None,
+                protocol_version: 1,
+                min_supported: 1,
+                adaptive_liveness: None,
+                threshold_params: None,
+                peer_identity: None,
+                peer_suite_preference: None,
+            })
+            .await
+            .unwrap();
+
+        engine.migrate_path("10.0.0.2:7000").await.unwrap();
+        assert_eq!(
+            *engine.session_manager.committed_endpoint.lock().unwrap(),
+            Some("10.0.0.2:7000".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn migrate_path_rejects_spoofed_response_without_committing() {
+        struct PathProbeSessionManager {
+            state: Arc<Mutex<SessionState>>,
+            committed_endpoint: Mutex<Option<String>>,
+            tamper_response: bool,
+        }
+
+        #[async_trait::async_trait(?Send)]
+        impl AsyncSessionManager for PathProbeSessionManager {
+            async fn establish_async(&self, _config: SessionConfig) -> Result<(), error::CoreError> {
+                *self.state.lock().unwrap() = SessionState::Connected;
+                Ok(())
+            }
+
+            async fn reconnect_async(&self) -> Result<(), error::CoreError> {
+                Ok(())
+            }
+
+            async fn terminate_async(&self) {
+                *self.state.lock().unwrap() = SessionState::Disconnected;
+            }
+
+            fn state(&self) -> SessionState {
+                *self.state.lock().unwrap()
+            }
+
+            async fn probe_path(
+                &self,
+                _new_endpoint: &str,
+                _connection_id: [u8; 16],
+                challenge: &[u8],
+            ) -> Result<Vec<u8>, error::CoreError> {
+                if self.tamper_response {
+                    return Ok(vec![0u8; challenge.len()]);
+                }
+                Ok(challenge.to_vec())
+            }
+
+            async fn commit_path(&self, new_endpoint: &str) -> Result<(), error::CoreError> {
+                *self.committed_endpoint.lock().unwrap() = Some(new_endpoint.to_string());
+                Ok(())
+            }
+        }
+
+        let recorder = Recorder::new();
+        let engine = CoreEngine::new(
+            PathProbeSessionManager {
+                state: Arc::new(Mutex::new(SessionState::Disconnected)),
+                committed_endpoint: Mutex::new(None),
+                tamper_response: true,
+            },
+            DummyStreamController {
+                recorder: recorder.clone(),
+            },
+            DummyCrypto {
+                recorder: recorder.clone(),
+                inner: P256SessionCrypto::new(P256KeyExchange),
+            },
+            DummyHeartbeatEmitter { recorder },
+        );
+
+        engine
+            .initialize(SessionConfig {
+                client_id: "roaming".into(),
+                heartbeat_interval_ms: 1_000,
+                peer_public_key: Some(sample_peer_key().await),
+                abr_config: None,
+                crypto_pool: None,
+                reconnect_strategy: None,
+                protocol_version: 1,
+                min_supported: 1,
+                adaptive_liveness: None,
+                threshold_params: None,
+                peer_identity: None,
+                peer_suite_preference: None,
+            })
+            .await
+            .unwrap();
+
+        let err = engine.migrate_path("10.0.0.2:7000").await.unwrap_err();
+        assert!(matches!(err, error::CoreError::PathValidationFailed));
+        assert!(engine
+            .session_manager
+            .committed_endpoint
+            .lock()
+            .unwrap()
+            .is_none());
+        assert_eq!(engine.state.state(), SessionState::Connected);
+    }
 }
@@ -0,0 +1,407 @@
+use crate::crypto::{self, SessionSecrets};
+use crate::error::{CoreError, CoreResult};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+/// Payloads at or above this size route through the parallel worker pool;
+/// smaller ones stay on the caller's thread to avoid dispatch overhead.
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 256 * 1024;
+
+/// Fixed plaintext chunk size the pool splits large payloads into.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Size, in bytes, of the chunk-count/chunk-size/total-length/message-id
+/// header `CryptoWorkerPool::encrypt` prefixes to its output.
+const HEADER_LEN: usize = 4 + 4 + 8 + 8;
+
+/// AEAD tag overhead added to each sealed chunk.
+const TAG_LEN: usize = 16;
+
+/// Tunables for [`CryptoWorkerPool`].
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkedCipherConfig {
+    /// Plaintext bytes per chunk dispatched to a worker.
+    pub chunk_size: usize,
+    /// Payload size, in bytes, at or above which chunking kicks in.
+    pub parallel_threshold: usize,
+    /// Number of persistent worker threads backing the pool.
+    pub worker_count: usize,
+}
+
+impl Default for ChunkedCipherConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            parallel_threshold: DEFAULT_PARALLEL_THRESHOLD,
+            worker_count: thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+        }
+    }
+}
+
+type ChunkJob = Box<dyn FnOnce() + Send>;
+
+/// Fixed pool of worker threads that seal/open fixed-size payload chunks in
+/// parallel, keeping `CoreEngine::encrypt_payload`/`decrypt_payload` off a
+/// single core for large transfers — the same crypto-pool design
+/// WireGuard-style datapaths use to saturate multi-core hosts. Each chunk's
+/// nonce is derived deterministically from the session secret, the
+/// message id `encrypt` is called with, and the chunk's index within that
+/// message (see `chunk_nonce`), so workers never collide on a nonce within
+/// one message, chunks can be opened out of order, and — critically — two
+/// different calls to `encrypt` under the same secret never reuse a chunk's
+/// (key, nonce) pair, since each gets a distinct message id. Callers must
+/// supply a message id that's never reused for the lifetime of `secrets`;
+/// see `EngineState::next_chunk_message_id`.
+///
+/// Payloads below `ChunkedCipherConfig::parallel_threshold` bypass the pool
+/// entirely; see `encrypt`/`decrypt`.
+pub struct CryptoWorkerPool {
+    config: ChunkedCipherConfig,
+    jobs: mpsc::Sender<ChunkJob>,
+}
+
+impl CryptoWorkerPool {
+    pub fn new(config: ChunkedCipherConfig) -> Self {
+        let (tx, rx) = mpsc::channel::<ChunkJob>();
+        let rx = Arc::new(Mutex::new(rx));
+        for _ in 0..config.worker_count.max(1) {
+            let rx = Arc::clone(&rx);
+            thread::spawn(move || loop {
+                let job = rx.lock().unwrap().recv();
+                match job {
+                    Ok(job) => job(),
+                    Err(_) => return,
+                }
+            });
+        }
+        Self { config, jobs: tx }
+    }
+
+    pub fn config(&self) -> ChunkedCipherConfig {
+        self.config
+    }
+
+    /// Encrypts `plaintext` under `secrets`, using `message_id` to keep this
+    /// call's chunk nonces distinct from every other message ever sealed
+    /// under the same `secrets` (see `EngineState::next_chunk_message_id`).
+    /// Below `parallel_threshold` this seals the whole buffer on the caller
+    /// thread via `crypto::aead_encrypt`, which ratchets its own nonce and
+    /// so has no need of `message_id`; at or above it, `plaintext` is split
+    /// into `chunk_size` pieces sealed in parallel and reassembled behind a
+    /// chunk-count/chunk-size/total-length/message-id header.
+    pub fn encrypt(
+        &self,
+        secrets: &SessionSecrets,
+        plaintext: &[u8],
+        message_id: u64,
+    ) -> CoreResult<Vec<u8>> {
+        if plaintext.len() < self.config.parallel_threshold {
+            return crypto::aead_encrypt(secrets, plaintext);
+        }
+
+        let chunk_size = self.config.chunk_size.max(1);
+        let chunks: Vec<&[u8]> = plaintext.chunks(chunk_size).collect();
+        let sealed = self.dispatch(secrets, message_id, &chunks, seal_chunk)?;
+
+        let mut framed = Vec::with_capacity(HEADER_LEN + plaintext.len() + sealed.len() * TAG_LEN);
+        framed.extend_from_slice(&(chunks.len() as u32).to_le_bytes());
+        framed.extend_from_slice(&(chunk_size as u32).to_le_bytes());
+        framed.extend_from_slice(&(plaintext.len() as u64).to_le_bytes());
+        framed.extend_from_slice(&message_id.to_le_bytes());
+        for chunk in sealed {
+            framed.extend_from_slice(&chunk);
+        }
+        Ok(framed)
+    }
+
+    /// Inverse of [`encrypt`](Self::encrypt) for its chunked output: validates
+    /// the chunk header, reopens every sealed chunk in parallel using the
+    /// message id carried in that header, and reassembles the plaintext in
+    /// order. A below-threshold payload that `encrypt` sealed with
+    /// `crypto::aead_encrypt` carries no chunk header and is rejected here.
+    pub fn decrypt(&self, secrets: &SessionSecrets, framed: &[u8]) -> CoreResult<Vec<u8>> {
+        let header = ChunkHeader::parse(framed)?;
+        let sealed_chunks = header.split_sealed_chunks(framed)?;
+        let opened = self.dispatch(secrets, header.message_id, &sealed_chunks, open_chunk)?;
+
+        let mut plaintext = Vec::with_capacity(header.total_len as usize);
+        for chunk in opened {
+            plaintext.extend_from_slice(&chunk);
+        }
+        Ok(plaintext)
+    }
+
+    /// Dispatches one job per entry in `inputs` to the pool and collects the
+    /// results back in input order, regardless of completion order.
+    fn dispatch(
+        &self,
+        secrets: &SessionSecrets,
+        message_id: u64,
+        inputs: &[&[u8]],
+        op: fn(&SessionSecrets, u64, u32, &[u8]) -> CoreResult<Vec<u8>>,
+    ) -> CoreResult<Vec<Vec<u8>>> {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        for (index, input) in inputs.iter().enumerate() {
+            let secrets = secrets.clone();
+            let input = input.to_vec();
+            let reply_tx = reply_tx.clone();
+            let index = index as u32;
+            self.jobs
+                .send(Box::new(move || {
+                    let result = op(&secrets, message_id, index, &input);
+                    let _ = reply_tx.send((index, result));
+                }))
+                .map_err(|_| CoreError::Crypto("crypto worker pool is shut down".into()))?;
+        }
+        drop(reply_tx);
+
+        let mut results: Vec<Option<Vec<u8>>> = (0..inputs.len()).map(|_| None).collect();
+        for _ in 0..inputs.len() {
+            let (index, result) = reply_rx
+                .recv()
+                .map_err(|_| CoreError::Crypto("crypto worker pool is shut down".into()))?;
+            results[index as usize] = Some(result?);
+        }
+        Ok(results
+            .into_iter()
+            .map(|chunk| chunk.expect("every dispatched index reports exactly once"))
+            .collect())
+    }
+}
+
+/// Parsed `chunk_count`/`chunk_size`/`total_len`/`message_id` header
+/// prefixed to a chunked frame, with the invariants needed to safely slice
+/// it validated up front.
+struct ChunkHeader {
+    chunk_count: u32,
+    chunk_size: u32,
+    total_len: u64,
+    message_id: u64,
+}
+
+impl ChunkHeader {
+    fn parse(framed: &[u8]) -> CoreResult<Self> {
+        if framed.len() < HEADER_LEN {
+            return Err(CoreError::Decrypt(
+                "chunked payload header truncated".into(),
+            ));
+        }
+        let chunk_count = u32::from_le_bytes(framed[0..4].try_into().unwrap());
+        let chunk_size = u32::from_le_bytes(framed[4..8].try_into().unwrap());
+        let total_len = u64::from_le_bytes(framed[8..16].try_into().unwrap());
+        let message_id = u64::from_le_bytes(framed[16..24].try_into().unwrap());
+
+        if chunk_count == 0 || chunk_size == 0 {
+            return Err(CoreError::Decrypt(
+                "chunked payload header is invalid".into(),
+            ));
+        }
+        let expected_count = total_len.div_ceil(chunk_size as u64);
+        if expected_count != chunk_count as u64 {
+            return Err(CoreError::Decrypt(
+                "chunked payload header is inconsistent with its declared length".into(),
+            ));
+        }
+
+        Ok(Self {
+            chunk_count,
+            chunk_size,
+            total_len,
+            message_id,
+        })
+    }
+
+    /// Slices the sealed chunk bodies out of `framed` (header included),
+    /// using `total_len`/`chunk_size` to recover each chunk's plaintext
+    /// length and thus its `+ TAG_LEN` ciphertext length.
+    fn split_sealed_chunks<'a>(&self, framed: &'a [u8]) -> CoreResult<Vec<&'a [u8]>> {
+        let mut body = &framed[HEADER_LEN..];
+        let mut remaining = self.total_len;
+        let mut chunks = Vec::with_capacity(self.chunk_count as usize);
+
+        for _ in 0..self.chunk_count {
+            let plain_len = remaining.min(self.chunk_size as u64) as usize;
+            let sealed_len = plain_len + TAG_LEN;
+            if body.len() < sealed_len {
+                return Err(CoreError::Decrypt("chunked payload body truncated".into()));
+            }
+            let (chunk, rest) = body.split_at(sealed_len);
+            chunks.push(chunk);
+            body = rest;
+            remaining -= plain_len as u64;
+        }
+
+        if !body.is_empty() {
+            return Err(CoreError::Decrypt(
+                "chunked payload has trailing bytes".into(),
+            ));
+        }
+        Ok(chunks)
+    }
+}
+
+fn seal_chunk(
+    secrets: &SessionSecrets,
+    message_id: u64,
+    index: u32,
+    plaintext: &[u8],
+) -> CoreResult<Vec<u8>> {
+    crypto::aead_seal_with_nonce(secrets, &chunk_nonce(secrets, message_id, index)?, plaintext)
+}
+
+fn open_chunk(
+    secrets: &SessionSecrets,
+    message_id: u64,
+    index: u32,
+    ciphertext: &[u8],
+) -> CoreResult<Vec<u8>> {
+    crypto::aead_open_with_nonce(secrets, &chunk_nonce(secrets, message_id, index)?, ciphertext)
+}
+
+/// Derives a 12-byte AEAD nonce deterministically from the session secret,
+/// the message id, and chunk index via HKDF. Because both sides can
+/// recompute it from `message_id`/`index` alone, it never needs to ride
+/// along in the frame as its own field (though `message_id` itself does,
+/// in the chunk header — see `ChunkHeader`), parallel workers sealing
+/// different chunks of the same message never risk a nonce collision, and
+/// distinct messages under the same `secrets` never collide either, since
+/// each call to `encrypt` supplies a `message_id` it never reuses.
+fn chunk_nonce(secrets: &SessionSecrets, message_id: u64, index: u32) -> CoreResult<[u8; 12]> {
+    let mut info = b"skybridge-parallel-chunk-nonce".to_vec();
+    info.extend_from_slice(&message_id.to_le_bytes());
+    info.extend_from_slice(&index.to_le_bytes());
+
+    let hk = Hkdf::<Sha256>::new(None, &secrets.shared_secret);
+    let mut nonce = [0u8; 12];
+    hk.expand(&info, &mut nonce)
+        .map_err(|e| CoreError::CryptoHandshake(format!("hkdf expand failed: {e}")))?;
+    Ok(nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{KeyExchangeProvider, P256KeyExchange};
+
+    async fn sample_secrets() -> SessionSecrets {
+        let local = P256KeyExchange.generate().await.unwrap();
+        let remote = P256KeyExchange.generate().await.unwrap();
+        let shared = P256KeyExchange
+            .derive_shared(&local, &remote.public_key)
+            .await
+            .unwrap();
+        SessionSecrets::new(
+            shared,
+            crypto::AeadSuiteId::Aes256Gcm,
+            &local.public_key,
+            &remote.public_key,
+        )
+        .unwrap()
+    }
+
+    /// Returns a pair of `SessionSecrets` sharing the same negotiated secret
+    /// but with complementary sender/receiver ratchet roles, as if derived by
+    /// the two peers of the same handshake.
+    async fn sample_secrets_pair() -> (SessionSecrets, SessionSecrets) {
+        let local = P256KeyExchange.generate().await.unwrap();
+        let remote = P256KeyExchange.generate().await.unwrap();
+        let shared = P256KeyExchange
+            .derive_shared(&local, &remote.public_key)
+            .await
+            .unwrap();
+        let ours = SessionSecrets::new(
+            shared.clone(),
+            crypto::AeadSuiteId::Aes256Gcm,
+            &local.public_key,
+            &remote.public_key,
+        )
+        .unwrap();
+        let theirs = SessionSecrets::new(
+            shared,
+            crypto::AeadSuiteId::Aes256Gcm,
+            &remote.public_key,
+            &local.public_key,
+        )
+        .unwrap();
+        (ours, theirs)
+    }
+
+    fn small_pool() -> CryptoWorkerPool {
+        CryptoWorkerPool::new(ChunkedCipherConfig {
+            chunk_size: 8,
+            parallel_threshold: 16,
+            worker_count: 3,
+        })
+    }
+
+    #[tokio::test]
+    async fn small_payloads_bypass_chunking() {
+        let pool = small_pool();
+        let (sender, receiver) = sample_secrets_pair().await;
+        let framed = pool.encrypt(&sender, b"short", 0).unwrap();
+        // Single-shot frame is a bare ratchet-sealed ciphertext, never our
+        // chunk header shape.
+        assert_eq!(crypto::aead_decrypt(&receiver, &framed).unwrap(), b"short");
+    }
+
+    #[tokio::test]
+    async fn large_payload_round_trips_through_chunks() {
+        let pool = small_pool();
+        let secrets = sample_secrets().await;
+        let plaintext: Vec<u8> = (0..100u16).map(|b| b as u8).collect();
+
+        let framed = pool.encrypt(&secrets, &plaintext, 0).unwrap();
+        let decrypted = pool.decrypt(&secrets, &framed).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[tokio::test]
+    async fn chunks_use_distinct_nonces() {
+        let secrets = sample_secrets().await;
+        assert_ne!(
+            chunk_nonce(&secrets, 0, 0).unwrap(),
+            chunk_nonce(&secrets, 0, 1).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn distinct_messages_use_distinct_chunk_nonces() {
+        let secrets = sample_secrets().await;
+        // Same chunk index, different message id: this is exactly the reuse
+        // a second large payload on the same session would otherwise hit.
+        assert_ne!(
+            chunk_nonce(&secrets, 0, 0).unwrap(),
+            chunk_nonce(&secrets, 1, 0).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn repeated_large_payloads_round_trip_without_nonce_reuse() {
+        let pool = small_pool();
+        let secrets = sample_secrets().await;
+        let first: Vec<u8> = (0..100u16).map(|b| b as u8).collect();
+        let second: Vec<u8> = (0..100u16).map(|b| (b * 3) as u8).collect();
+
+        let framed_first = pool.encrypt(&secrets, &first, 0).unwrap();
+        let framed_second = pool.encrypt(&secrets, &second, 1).unwrap();
+        assert_ne!(framed_first, framed_second);
+        assert_eq!(pool.decrypt(&secrets, &framed_first).unwrap(), first);
+        assert_eq!(pool.decrypt(&secrets, &framed_second).unwrap(), second);
+    }
+
+    #[tokio::test]
+    async fn tampered_chunk_header_is_rejected() {
+        let pool = small_pool();
+        let secrets = sample_secrets().await;
+        let plaintext: Vec<u8> = (0..50u8).collect();
+        let mut framed = pool.encrypt(&secrets, &plaintext, 0).unwrap();
+
+        framed[0..4].copy_from_slice(&99u32.to_le_bytes());
+        let err = pool.decrypt(&secrets, &framed).unwrap_err();
+        assert!(matches!(err, CoreError::Decrypt(_)));
+    }
+}
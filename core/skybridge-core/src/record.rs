@@ -0,0 +1,234 @@
+//! Length-prefixed, padded record framing over `SessionCryptoProvider`.
+//!
+//! Modeled loosely on AIRA's session framing: the raw `encrypt`/`decrypt`
+//! primitive seals one buffer with no length hiding, so plaintext sizes leak
+//! directly into ciphertext sizes on the wire. `RecordLayer` pads every
+//! plaintext up to the next rung of a fixed size ladder before sealing (with
+//! the true length authenticated inside the AEAD so `read_record` can strip
+//! the padding back off), and prefixes the sealed record with its own
+//! 4-byte big-endian length so a stream reader knows how much to read.
+
+use crate::crypto::{SessionCryptoProvider, SessionSecrets};
+use crate::error::CoreError;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Size ladder records are padded up to, smallest rung first. Padding a
+/// record to one of a small, fixed set of sizes hides its true length from
+/// an observer who can only see ciphertext sizes on the wire.
+pub const PADDING_LADDER: [usize; 5] = [256, 1024, 4096, 16384, 65536];
+
+/// The largest padded record `RecordLayer` will seal; a plaintext (plus its
+/// 4-byte length prefix) that doesn't fit even the top rung is rejected
+/// rather than sent unpadded.
+pub const PADDED_MAX_SIZE: usize = PADDING_LADDER[PADDING_LADDER.len() - 1];
+
+/// Both AEAD suites this crate supports append a 16-byte authentication tag.
+const AEAD_TAG_OVERHEAD: usize = 16;
+
+/// Upper bound on the sealed bytes `RecordLayer::read_record` will allocate
+/// for a single record, independent of whatever length prefix a peer sends,
+/// so a forged or corrupted prefix can't be used to force an unbounded
+/// allocation.
+const MAX_RECORD_LEN: usize = PADDED_MAX_SIZE + AEAD_TAG_OVERHEAD;
+
+/// Finds the smallest `PADDING_LADDER` rung that fits `framed_len` (the
+/// plaintext plus its 4-byte in-record length prefix), rejecting records
+/// that don't fit even the largest rung.
+fn next_bucket(framed_len: usize) -> Result<usize, CoreError> {
+    PADDING_LADDER
+        .iter()
+        .copied()
+        .find(|&rung| rung >= framed_len)
+        .ok_or_else(|| {
+            CoreError::Encrypt(format!(
+                "record of {framed_len} bytes exceeds PADDED_MAX_SIZE ({PADDED_MAX_SIZE})"
+            ))
+        })
+}
+
+/// A streaming record framing built on top of a `SessionCryptoProvider` and
+/// its negotiated `SessionSecrets`, turning the per-message AEAD primitive
+/// into a usable transport with uniform, length-hiding packet sizes.
+pub struct RecordLayer<'a, P: SessionCryptoProvider> {
+    crypto: &'a P,
+    secrets: &'a SessionSecrets,
+}
+
+impl<'a, P: SessionCryptoProvider> RecordLayer<'a, P> {
+    pub fn new(crypto: &'a P, secrets: &'a SessionSecrets) -> Self {
+        Self { crypto, secrets }
+    }
+
+    /// Pads `plaintext` up to the next `PADDING_LADDER` rung, seals it, and
+    /// writes the sealed record to `writer` behind a 4-byte big-endian
+    /// length prefix.
+    pub async fn write_record<W: AsyncWrite + Unpin>(
+        &self,
+        writer: &mut W,
+        plaintext: &[u8],
+    ) -> Result<(), CoreError> {
+        let sealed = self.seal_record(plaintext)?;
+        let prefix_len = u32::try_from(sealed.len())
+            .map_err(|_| CoreError::Encrypt("sealed record too large to frame".into()))?;
+        writer
+            .write_all(&prefix_len.to_be_bytes())
+            .await
+            .map_err(|e| CoreError::Stream(e.to_string()))?;
+        writer
+            .write_all(&sealed)
+            .await
+            .map_err(|e| CoreError::Stream(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Reads one record written by `write_record` from `reader`: the 4-byte
+    /// length prefix, then that many sealed bytes (rejected past
+    /// `MAX_RECORD_LEN` to bound allocation), opening and stripping padding
+    /// to recover the original plaintext.
+    pub async fn read_record<R: AsyncRead + Unpin>(
+        &self,
+        reader: &mut R,
+    ) -> Result<Vec<u8>, CoreError> {
+        let mut prefix = [0u8; 4];
+        reader
+            .read_exact(&mut prefix)
+            .await
+            .map_err(|e| CoreError::Decrypt(format!("truncated record length prefix: {e}")))?;
+        let sealed_len = u32::from_be_bytes(prefix) as usize;
+        if sealed_len > MAX_RECORD_LEN {
+            return Err(CoreError::Decrypt(format!(
+                "record length {sealed_len} exceeds the {MAX_RECORD_LEN}-byte max-record guard"
+            )));
+        }
+
+        let mut sealed = vec![0u8; sealed_len];
+        reader
+            .read_exact(&mut sealed)
+            .await
+            .map_err(|e| CoreError::Decrypt(format!("truncated record body: {e}")))?;
+        self.open_record(&sealed)
+    }
+
+    fn seal_record(&self, plaintext: &[u8]) -> Result<Vec<u8>, CoreError> {
+        let framed_len = 4 + plaintext.len();
+        let bucket = next_bucket(framed_len)?;
+
+        let mut padded = Vec::with_capacity(bucket);
+        padded.extend_from_slice(&(plaintext.len() as u32).to_be_bytes());
+        padded.extend_from_slice(plaintext);
+        padded.resize(bucket, 0);
+
+        self.crypto.encrypt(self.secrets, &padded)
+    }
+
+    fn open_record(&self, sealed: &[u8]) -> Result<Vec<u8>, CoreError> {
+        let padded = self.crypto.decrypt(self.secrets, sealed)?;
+        if padded.len() < 4 {
+            return Err(CoreError::Decrypt(
+                "record shorter than its length prefix".into(),
+            ));
+        }
+        let (len_bytes, rest) = padded.split_at(4);
+        let true_len = u32::from_be_bytes(len_bytes.try_into().unwrap()) as usize;
+        if true_len > rest.len() {
+            return Err(CoreError::Decrypt(
+                "record's authenticated length exceeds its padded size".into(),
+            ));
+        }
+        Ok(rest[..true_len].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{KeyExchangeProvider, P256KeyExchange, P256SessionCrypto};
+    use std::io::Cursor;
+
+    async fn handshake_pair() -> (
+        (P256SessionCrypto<P256KeyExchange>, SessionSecrets),
+        (P256SessionCrypto<P256KeyExchange>, SessionSecrets),
+    ) {
+        let local_crypto = P256SessionCrypto::new(P256KeyExchange);
+        let remote_crypto = P256SessionCrypto::new(P256KeyExchange);
+
+        let local_pub = local_crypto.begin_handshake().await.unwrap();
+        let remote_pub = remote_crypto.begin_handshake().await.unwrap();
+        let local_secrets = local_crypto.finalize_handshake(&remote_pub).await.unwrap();
+        let remote_secrets = remote_crypto.finalize_handshake(&local_pub).await.unwrap();
+
+        ((local_crypto, local_secrets), (remote_crypto, remote_secrets))
+    }
+
+    #[tokio::test]
+    async fn record_round_trips_and_hides_length_behind_the_ladder() {
+        let ((sender, sender_secrets), (receiver, receiver_secrets)) = handshake_pair().await;
+        let sender_layer = RecordLayer::new(&sender, &sender_secrets);
+        let receiver_layer = RecordLayer::new(&receiver, &receiver_secrets);
+
+        let mut wire = Vec::new();
+        sender_layer.write_record(&mut wire, b"short").await.unwrap();
+        let short_wire_len = wire.len();
+
+        let mut wire2 = Vec::new();
+        sender_layer
+            .write_record(&mut wire2, &vec![0u8; 200])
+            .await
+            .unwrap();
+
+        // Both plaintexts land in the same 256-byte rung, so their sealed
+        // records (and thus the wire length prefix) are identical in size.
+        assert_eq!(short_wire_len, wire2.len());
+
+        let mut cursor = Cursor::new(wire);
+        let decrypted = receiver_layer.read_record(&mut cursor).await.unwrap();
+        assert_eq!(decrypted, b"short");
+    }
+
+    #[tokio::test]
+    async fn oversized_record_is_rejected_before_sealing() {
+        let ((sender, sender_secrets), _) = handshake_pair().await;
+        let layer = RecordLayer::new(&sender, &sender_secrets);
+
+        let mut wire = Vec::new();
+        let err = layer
+            .write_record(&mut wire, &vec![0u8; PADDED_MAX_SIZE])
+            .await
+            .expect_err("plaintext plus prefix exceeds the top rung");
+        assert!(matches!(err, CoreError::Encrypt(_)));
+    }
+
+    #[tokio::test]
+    async fn truncated_record_is_rejected() {
+        let ((sender, sender_secrets), (receiver, receiver_secrets)) = handshake_pair().await;
+        let sender_layer = RecordLayer::new(&sender, &sender_secrets);
+        let receiver_layer = RecordLayer::new(&receiver, &receiver_secrets);
+
+        let mut wire = Vec::new();
+        sender_layer.write_record(&mut wire, b"hello").await.unwrap();
+        wire.truncate(wire.len() - 1);
+
+        let mut cursor = Cursor::new(wire);
+        let err = receiver_layer
+            .read_record(&mut cursor)
+            .await
+            .expect_err("truncated body should fail");
+        assert!(matches!(err, CoreError::Decrypt(_)));
+    }
+
+    #[tokio::test]
+    async fn oversized_length_prefix_is_rejected_before_allocating() {
+        let ((_, _), (receiver, receiver_secrets)) = handshake_pair().await;
+        let layer = RecordLayer::new(&receiver, &receiver_secrets);
+
+        let mut wire = Vec::new();
+        wire.extend_from_slice(&(MAX_RECORD_LEN as u32 + 1).to_be_bytes());
+
+        let mut cursor = Cursor::new(wire);
+        let err = layer
+            .read_record(&mut cursor)
+            .await
+            .expect_err("length prefix past the max-record guard should fail");
+        assert!(matches!(err, CoreError::Decrypt(_)));
+    }
+}
@@ -1,5 +1,9 @@
 use crate::error::{CoreError, CoreResult};
+use rand_core::{OsRng, RngCore};
+use std::collections::{HashMap, VecDeque};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
 
 /// Represents runtime state of a session.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -56,7 +60,14 @@ impl SessionStateMachine {
                 SessionState::Disconnected,
             ],
             SessionState::Connecting => &[SessionState::Disconnected],
-            SessionState::Connected => &[SessionState::Connecting, SessionState::Reconnecting],
+            // `Disconnected` is allowed so `CoreEngine::resume` can restore a
+            // session straight from a resumption ticket without re-running
+            // the handshake.
+            SessionState::Connected => &[
+                SessionState::Connecting,
+                SessionState::Reconnecting,
+                SessionState::Disconnected,
+            ],
             SessionState::Reconnecting => &[SessionState::Connected],
             SessionState::ShuttingDown => &[
                 SessionState::Connected,
@@ -94,6 +105,64 @@ pub struct SessionConfig {
     pub client_id: String,
     pub heartbeat_interval_ms: u64,
     pub peer_public_key: Option<Vec<u8>>,
+    /// Adaptive bitrate tuning; `None` leaves the stream at its static rate.
+    pub abr_config: Option<crate::stream::AbrConfig>,
+    /// Parallel crypto worker pool tuning; `None` keeps
+    /// `encrypt_payload`/`decrypt_payload` single-threaded regardless of
+    /// payload size.
+    pub crypto_pool: Option<crate::pool::ChunkedCipherConfig>,
+    /// Backoff policy for `CoreEngine::reconnect`; `None` falls back to
+    /// `ReconnectStrategy::default()`.
+    pub reconnect_strategy: Option<ReconnectStrategy>,
+    /// Highest wire-protocol version this side is willing to speak.
+    /// `CoreEngine::initialize` negotiates down to
+    /// `min(protocol_version, crate::PROTOCOL_VERSION_MAX)`.
+    pub protocol_version: u16,
+    /// Oldest wire-protocol version this side will accept. Rejected with
+    /// `CoreError::UnsupportedProtocol` if it exceeds the engine's max supported
+    /// version, since no common version would exist.
+    pub min_supported: u16,
+    /// Tunables for `CoreEngine::check_liveness_auto`'s RTT-derived
+    /// deadline; `None` falls back to `AdaptiveLivenessConfig::default()`.
+    pub adaptive_liveness: Option<AdaptiveLivenessConfig>,
+    /// Declares that the engine was constructed with a
+    /// `crate::threshold::ThresholdSessionCrypto` provider and names the
+    /// M-of-N parameters it was dealt under. `CoreEngine::initialize`
+    /// rejects a mismatch between this and `crypto.algorithm()` with
+    /// `CoreError::InvalidConfig`, so a caller can't silently fall back to
+    /// single-peer secrets while believing it configured threshold custody.
+    /// `None` for the normal single-peer `P256SessionCrypto` path.
+    pub threshold_params: Option<crate::threshold::ThresholdParams>,
+    /// The peer's long-lived identity and its handshake transcript material,
+    /// obtained out-of-band the same way `peer_public_key` is. When set,
+    /// `CoreEngine::initialize` authenticates the handshake against it via
+    /// `SessionCryptoProvider::finalize_handshake_authenticated` instead of
+    /// the plain, unauthenticated `finalize_handshake`, rejecting with
+    /// `CoreError::InvalidConfig` if the crypto provider doesn't support
+    /// identity-bound handshakes. `None` skips identity verification
+    /// entirely, leaving the ephemeral ECDH exchange open to on-path
+    /// substitution.
+    pub peer_identity: Option<PeerIdentity>,
+    /// The peer's AEAD suites, ranked by its measured throughput,
+    /// advertised alongside its ephemeral offer the same way
+    /// `peer_public_key` is. When set, `CoreEngine::initialize` negotiates
+    /// the suite via `SessionCryptoProvider::finalize_handshake_with_suite`
+    /// instead of `finalize_handshake`'s fixed suite. Ignored if
+    /// `peer_identity` is also set, since `finalize_handshake_authenticated`
+    /// doesn't negotiate; identity verification wins. `None` keeps the
+    /// fixed-suite handshake.
+    pub peer_suite_preference: Option<Vec<crate::crypto::AeadSuiteId>>,
+}
+
+/// The peer's long-lived Ed25519 identity public key, the random challenge
+/// it emitted alongside its ephemeral offer, and its signature over the
+/// resulting transcript — everything `finalize_handshake_authenticated`
+/// needs to authenticate the peer named by `SessionConfig::peer_identity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerIdentity {
+    pub public_key: [u8; 32],
+    pub random: [u8; 64],
+    pub signature: [u8; 64],
 }
 
 impl SessionConfig {
@@ -129,6 +198,31 @@ pub trait AsyncSessionManager {
     async fn reconnect_async(&self) -> CoreResult<()>;
     async fn terminate_async(&self);
     fn state(&self) -> SessionState;
+
+    /// Sends `challenge` (a token sealed under the session's existing
+    /// `SessionSecrets`) to the candidate `new_endpoint` and returns
+    /// whatever the peer echoes back, without disturbing the currently
+    /// active path. `connection_id` identifies which session this probe
+    /// belongs to, for managers that multiplex several sessions.
+    ///
+    /// Used by `CoreEngine::migrate_path` to validate a new endpoint before
+    /// committing to it. Implementations that don't support path migration
+    /// can rely on the default, which rejects it.
+    async fn probe_path(
+        &self,
+        _new_endpoint: &str,
+        _connection_id: [u8; 16],
+        _challenge: &[u8],
+    ) -> CoreResult<Vec<u8>> {
+        Err(CoreError::PathValidationFailed)
+    }
+
+    /// Re-points this session manager at `new_endpoint`, called only after
+    /// `probe_path`'s challenge/response round trip has succeeded, without
+    /// renegotiating crypto.
+    async fn commit_path(&self, _new_endpoint: &str) -> CoreResult<()> {
+        Err(CoreError::PathValidationFailed)
+    }
 }
 
 /// Heartbeat hook for the platform layer.
@@ -137,9 +231,466 @@ pub trait HeartbeatEmitter {
     async fn emit(&self) -> CoreResult<()>;
 }
 
+/// Consecutive-failure threshold before a breaker trips to `Open`.
+const CIRCUIT_FAILURE_THRESHOLD: u32 = 5;
+
+/// Cooldown schedule per consecutive trip: 1 min, 5 min, 30 min, capped at 1 day.
+const CIRCUIT_COOLDOWNS_MS: &[u64] = &[60_000, 5 * 60_000, 30 * 60_000];
+const CIRCUIT_MAX_COOLDOWN_MS: u64 = 24 * 60 * 60 * 1_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct Breaker {
+    circuit_state: CircuitState,
+    consecutive_failures: u32,
+    trip_count: u32,
+    opened_at: Option<Instant>,
+}
+
+impl Breaker {
+    fn new() -> Self {
+        Self {
+            circuit_state: CircuitState::Closed,
+            consecutive_failures: 0,
+            trip_count: 0,
+            opened_at: None,
+        }
+    }
+
+    fn cooldown(&self) -> Duration {
+        let index = self.trip_count.saturating_sub(1) as usize;
+        let ms = CIRCUIT_COOLDOWNS_MS
+            .get(index)
+            .copied()
+            .unwrap_or(CIRCUIT_MAX_COOLDOWN_MS)
+            .min(CIRCUIT_MAX_COOLDOWN_MS);
+        Duration::from_millis(ms)
+    }
+
+    /// Returns `Ok(())` if a reconnect attempt may proceed, advancing `Open`
+    /// breakers to `HalfOpen` once their cooldown has elapsed.
+    fn should_try(&mut self) -> CoreResult<()> {
+        match self.circuit_state {
+            CircuitState::Closed | CircuitState::HalfOpen => Ok(()),
+            CircuitState::Open => {
+                let opened_at = self.opened_at.unwrap_or_else(Instant::now);
+                let cooldown = self.cooldown();
+                let elapsed = opened_at.elapsed();
+                if elapsed >= cooldown {
+                    self.circuit_state = CircuitState::HalfOpen;
+                    Ok(())
+                } else {
+                    let retry_in_ms = cooldown.saturating_sub(elapsed).as_millis() as u64;
+                    Err(CoreError::CircuitOpen { retry_in_ms })
+                }
+            }
+        }
+    }
+
+    fn record_success(&mut self) {
+        self.circuit_state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+        self.trip_count = 0;
+        self.opened_at = None;
+    }
+
+    fn record_failure(&mut self) {
+        match self.circuit_state {
+            CircuitState::HalfOpen => self.trip(),
+            CircuitState::Closed => {
+                self.consecutive_failures += 1;
+                if self.consecutive_failures >= CIRCUIT_FAILURE_THRESHOLD {
+                    self.trip();
+                }
+            }
+            CircuitState::Open => {}
+        }
+    }
+
+    fn trip(&mut self) {
+        self.circuit_state = CircuitState::Open;
+        self.trip_count += 1;
+        self.opened_at = Some(Instant::now());
+    }
+}
+
+/// Registry of per-device/client circuit breakers gating `reconnect_async`.
+///
+/// Shared across sessions so a flapping peer is only probed on the cooldown
+/// schedule instead of being hammered on every `CoreEngine::reconnect` call.
+#[derive(Debug, Default)]
+pub struct BreakerRegistry {
+    breakers: Mutex<HashMap<String, Breaker>>,
+}
+
+impl BreakerRegistry {
+    pub fn new() -> Self {
+        Self {
+            breakers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns `Ok(())` if `key` may attempt a reconnect right now, or
+    /// `CoreError::CircuitOpen` with the remaining cooldown otherwise.
+    pub fn should_try(&self, key: &str) -> CoreResult<()> {
+        let mut guard = self.breakers.lock().unwrap();
+        guard.entry(key.to_string()).or_insert_with(Breaker::new).should_try()
+    }
+
+    /// Closes the breaker for `key` after a successful reconnect.
+    pub fn record_success(&self, key: &str) {
+        let mut guard = self.breakers.lock().unwrap();
+        guard
+            .entry(key.to_string())
+            .or_insert_with(Breaker::new)
+            .record_success();
+    }
+
+    /// Records a failed reconnect attempt for `key`, tripping the breaker
+    /// once the consecutive-failure threshold is crossed.
+    pub fn record_failure(&self, key: &str) {
+        let mut guard = self.breakers.lock().unwrap();
+        guard
+            .entry(key.to_string())
+            .or_insert_with(Breaker::new)
+            .record_failure();
+    }
+}
+
+/// Exponential backoff with full jitter for `CoreEngine::reconnect_with`.
+///
+/// `delay_for(attempt)` computes `min(max_delay, initial * multiplier^attempt)`;
+/// the caller then sleeps a random value drawn from `[0, delay]` so that many
+/// clients backing off simultaneously don't retry in lockstep.
+#[derive(Debug, Clone, Copy)]
+pub struct ReconnectStrategy {
+    pub initial_delay: Duration,
+    pub multiplier: f64,
+    pub max_delay: Duration,
+    /// `None` retries forever; `Some(n)` gives up once `n` retries have also
+    /// failed, i.e. after `n + 1` failed attempts in all.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectStrategy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+            max_attempts: Some(8),
+        }
+    }
+}
+
+impl ReconnectStrategy {
+    /// The capped backoff delay before attempt number `attempt` (0-indexed).
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let scaled = self.initial_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        Duration::from_secs_f64(scaled).min(self.max_delay)
+    }
+
+    /// Draws a full-jitter sleep duration in `[0, delay_for(attempt)]`.
+    pub fn jittered_delay_for(&self, attempt: u32) -> Duration {
+        let cap = self.delay_for(attempt);
+        if cap.is_zero() {
+            return cap;
+        }
+        let cap_nanos = cap.as_nanos().min(u64::MAX as u128) as u64;
+        let sampled = OsRng.next_u64() % (cap_nanos + 1);
+        Duration::from_nanos(sampled)
+    }
+
+    pub fn attempts_exhausted(&self, attempt: u32) -> bool {
+        matches!(self.max_attempts, Some(max) if attempt >= max)
+    }
+}
+
+/// Tunables for the background liveness monitor spawned via
+/// `CoreEngine::spawn_liveness_monitor`.
+///
+/// The monitor polls `last_heartbeat` every `poll_interval`; once the gap
+/// since the last heartbeat exceeds `missed_interval_tolerance` heartbeat
+/// intervals plus a jittered grace period, it reports a
+/// [`LivenessFailure`] and drives the session through a reconnect.
+#[derive(Debug, Clone, Copy)]
+pub struct LivenessConfig {
+    /// Consecutive heartbeat intervals of silence tolerated before a gap
+    /// counts as a liveness failure.
+    pub missed_interval_tolerance: u32,
+    /// How often the monitor polls `last_heartbeat`.
+    pub poll_interval: Duration,
+    /// Upper bound of a random grace period added on top of the tolerance
+    /// window (full jitter, like `ReconnectStrategy`) so a single heartbeat
+    /// landing just past the boundary doesn't immediately thrash the state
+    /// machine into `Reconnecting`.
+    pub max_grace_jitter: Duration,
+}
+
+impl Default for LivenessConfig {
+    fn default() -> Self {
+        Self {
+            missed_interval_tolerance: 3,
+            poll_interval: Duration::from_millis(500),
+            max_grace_jitter: Duration::from_secs(1),
+        }
+    }
+}
+
+impl LivenessConfig {
+    /// The silence gap, before jitter, that counts as a liveness failure.
+    pub fn tolerance_window(&self, heartbeat_interval_ms: u64) -> Duration {
+        Duration::from_millis(heartbeat_interval_ms) * self.missed_interval_tolerance
+    }
+
+    /// Draws a full-jitter grace period in `[0, max_grace_jitter]`, mirroring
+    /// `ReconnectStrategy::jittered_delay_for`.
+    pub fn jittered_grace(&self) -> Duration {
+        if self.max_grace_jitter.is_zero() {
+            return self.max_grace_jitter;
+        }
+        let cap_nanos = self.max_grace_jitter.as_nanos().min(u64::MAX as u128) as u64;
+        let sampled = OsRng.next_u64() % (cap_nanos + 1);
+        Duration::from_nanos(sampled)
+    }
+}
+
+/// Reported once per detected gap, before the liveness monitor drives the
+/// state machine into `Reconnecting` and retries the session.
+#[derive(Debug, Clone, Copy)]
+pub struct LivenessFailure {
+    pub missed_intervals: u32,
+    pub elapsed: Duration,
+}
+
+/// Tunables for `CoreEngine::check_liveness_auto`'s RTT-derived deadline,
+/// computed from an [`RttEstimator`] as `srtt + 4*rttvar` and clamped to
+/// `[min_deadline_ms, max_deadline_ms]`.
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveLivenessConfig {
+    /// Floor for the computed deadline, regardless of how tight `srtt` is.
+    pub min_deadline_ms: u64,
+    /// Ceiling for the computed deadline, regardless of how noisy `srtt` is.
+    pub max_deadline_ms: u64,
+    /// Multiple of `heartbeat_interval_ms` used as the deadline before any
+    /// heartbeat ack has landed and seeded an `RttEstimator`.
+    pub fallback_multiplier: u32,
+}
+
+impl Default for AdaptiveLivenessConfig {
+    fn default() -> Self {
+        Self {
+            min_deadline_ms: 1_000,
+            max_deadline_ms: 30_000,
+            fallback_multiplier: 3,
+        }
+    }
+}
+
+/// Gain applied to the smoothed RTT estimate on each sample (Jacobson/Karels).
+const RTT_SRTT_GAIN: f64 = 1.0 / 8.0;
+/// Gain applied to the RTT variance estimate on each sample.
+const RTT_RTTVAR_GAIN: f64 = 1.0 / 4.0;
+
+/// Smoothed heartbeat round-trip-time estimate, updated per ack with the
+/// same EWMA scheme TCP's RTO estimator uses: `srtt` tracks the mean with
+/// gain 1/8, `rttvar` tracks the mean absolute deviation with gain 1/4.
+#[derive(Debug, Clone, Copy)]
+pub struct RttEstimator {
+    srtt_ms: f64,
+    rttvar_ms: f64,
+}
+
+impl RttEstimator {
+    /// Seeds the estimator from a single sample: `rttvar` starts at half the
+    /// sample, `srtt` at the sample itself, per Jacobson/Karels.
+    pub fn from_sample(sample_ms: f64) -> Self {
+        Self {
+            srtt_ms: sample_ms,
+            rttvar_ms: sample_ms / 2.0,
+        }
+    }
+
+    pub fn update(&mut self, sample_ms: f64) {
+        self.rttvar_ms = (1.0 - RTT_RTTVAR_GAIN) * self.rttvar_ms
+            + RTT_RTTVAR_GAIN * (self.srtt_ms - sample_ms).abs();
+        self.srtt_ms = (1.0 - RTT_SRTT_GAIN) * self.srtt_ms + RTT_SRTT_GAIN * sample_ms;
+    }
+
+    pub fn srtt_ms(&self) -> f64 {
+        self.srtt_ms
+    }
+
+    /// `srtt + 4*rttvar`, clamped to `config`'s `[min_deadline_ms,
+    /// max_deadline_ms]`.
+    pub fn deadline_ms(&self, config: &AdaptiveLivenessConfig) -> u64 {
+        let raw_ms = self.srtt_ms + 4.0 * self.rttvar_ms;
+        (raw_ms.round() as u64).clamp(config.min_deadline_ms, config.max_deadline_ms)
+    }
+}
+
+/// Session-lifecycle and stream events reported through an [`EventProvider`],
+/// mirroring the FFI layer's `SkybridgeEventKind` at the Rust type level so
+/// `AsyncSessionManager` implementors can consume the same event stream a
+/// native host would otherwise have to poll for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionEvent {
+    Connected,
+    Disconnected,
+    HeartbeatAck,
+    InputReceived(Vec<u8>),
+    /// One plaintext recovered by `CoreEngine::feed_frames` from a complete,
+    /// in-sequence frame.
+    FrameDecoded(Vec<u8>),
+    Reconnected,
+    ReconnectExhausted,
+    HeartbeatTimeout,
+    BitrateChanged { target_bitrate_bps: u64 },
+    /// Reports that `count` non-critical events have been coalesced away
+    /// since the last marker, per [`EventBroker`]'s overflow policy.
+    EventsDropped { count: u64 },
+    /// Reports that an `_async` FFI call (e.g.
+    /// `ffi::skybridge_engine_connect_async`) submitted to the engine's
+    /// background worker thread has finished. `error_code` is `0` on
+    /// success; any other value is the `SkybridgeErrorCode` the
+    /// synchronous equivalent of the same call would have returned.
+    OperationComplete { request_id: u64, error_code: i32 },
+}
+
+impl SessionEvent {
+    /// Lifecycle milestones [`EventBroker`]'s coalescing policy never
+    /// discards, even under sustained overflow.
+    pub fn is_critical(&self) -> bool {
+        matches!(
+            self,
+            SessionEvent::Connected
+                | SessionEvent::Disconnected
+                | SessionEvent::HeartbeatTimeout
+                | SessionEvent::ReconnectExhausted
+        )
+    }
+}
+
+/// Async, loss-aware alternative to repeatedly polling for events.
+///
+/// Implementors back `next_event` with an internal channel so a caller can
+/// `.await` the next [`SessionEvent`] instead of spinning a poll loop. See
+/// [`EventBroker`] for the bounded, coalescing implementation shared with
+/// the FFI layer's `skybridge_engine_poll_events` ABI.
+#[async_trait::async_trait(?Send)]
+pub trait EventProvider {
+    async fn next_event(&self) -> Option<SessionEvent>;
+}
+
+/// Bounded event buffer shared by push ([`EventProvider::next_event`]) and
+/// pull (repeated [`EventBroker::poll`]) consumers, so a host can pick either
+/// delivery style without the two diverging.
+///
+/// When a push would exceed `capacity`, the oldest non-critical event (see
+/// [`SessionEvent::is_critical`]) is coalesced away to make room; `Connected`,
+/// `Disconnected`, `HeartbeatTimeout`, and `ReconnectExhausted` are never
+/// dropped this way. Each coalesced event increments `dropped_events`, and a
+/// trailing `EventsDropped` marker carrying the running total is appended (or
+/// bumped in place, if one is already queued) so consumers learn about the
+/// loss instead of silently missing it.
+#[derive(Debug)]
+pub struct EventBroker {
+    capacity: usize,
+    queue: Mutex<VecDeque<SessionEvent>>,
+    dropped_events: Mutex<u64>,
+    notify: Notify,
+}
+
+impl EventBroker {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            queue: Mutex::new(VecDeque::new()),
+            dropped_events: Mutex::new(0),
+            notify: Notify::new(),
+        }
+    }
+
+    /// Pushes `event`, coalescing away the oldest non-critical event when the
+    /// buffer is full instead of dropping `event` itself or blocking.
+    pub fn push(&self, event: SessionEvent) {
+        let mut queue = self.queue.lock().unwrap();
+
+        if self.evict_for_capacity(&mut queue)
+            && !queue
+                .iter()
+                .any(|queued| matches!(queued, SessionEvent::EventsDropped { .. }))
+        {
+            self.evict_for_capacity(&mut queue);
+            queue.push_back(SessionEvent::EventsDropped { count: 0 });
+        }
+
+        self.evict_for_capacity(&mut queue);
+        let count = self.dropped_events();
+        if let Some(SessionEvent::EventsDropped { count: marker_count }) = queue
+            .iter_mut()
+            .find(|queued| matches!(queued, SessionEvent::EventsDropped { .. }))
+        {
+            *marker_count = count;
+        }
+        queue.push_back(event);
+        self.notify.notify_one();
+    }
+
+    /// Evicts one event to keep `queue` under `capacity`, returning whether
+    /// an eviction happened. Prefers the oldest non-critical event; falls
+    /// back to the oldest event outright if the buffer is saturated with
+    /// lifecycle events.
+    fn evict_for_capacity(&self, queue: &mut VecDeque<SessionEvent>) -> bool {
+        if queue.len() < self.capacity {
+            return false;
+        }
+        if let Some(pos) = queue.iter().position(|queued| !queued.is_critical()) {
+            queue.remove(pos);
+        } else {
+            queue.pop_front();
+        }
+        *self.dropped_events.lock().unwrap() += 1;
+        true
+    }
+
+    /// Non-blocking drain, used by the FFI poll ABI.
+    pub fn poll(&self) -> Option<SessionEvent> {
+        self.queue.lock().unwrap().pop_front()
+    }
+
+    /// Drops every queued event without affecting the `dropped_events` tally.
+    pub fn clear(&self) {
+        self.queue.lock().unwrap().clear();
+    }
+
+    pub fn dropped_events(&self) -> u64 {
+        *self.dropped_events.lock().unwrap()
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl EventProvider for EventBroker {
+    async fn next_event(&self) -> Option<SessionEvent> {
+        loop {
+            if let Some(event) = self.poll() {
+                return Some(event);
+            }
+            self.notify.notified().await;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
 
     #[test]
     fn transition_table_allows_happy_path() {
@@ -159,24 +710,40 @@ mod tests {
     fn invalid_transition_reports_expected_states() {
         let machine = SessionStateMachine::new();
         let err = machine
-            .transition(SessionState::Connected)
-            .expect_err("cannot connect directly from disconnected");
+            .transition(SessionState::Reconnecting)
+            .expect_err("cannot enter reconnecting without first being connected");
 
         match err {
             CoreError::InvalidState { expected, actual } => {
                 assert_eq!(actual, SessionState::Disconnected);
-                assert_eq!(expected, "Connecting|Reconnecting");
+                assert_eq!(expected, "Connected");
             }
             other => panic!("unexpected error: {:?}", other),
         }
     }
 
+    #[test]
+    fn connected_is_reachable_directly_from_disconnected_for_ticket_resumption() {
+        let machine = SessionStateMachine::new();
+        machine.transition(SessionState::Connected).unwrap();
+        assert_eq!(machine.current(), SessionState::Connected);
+    }
+
     #[test]
     fn config_validation_rejects_bad_inputs() {
         let empty_id = SessionConfig {
             client_id: "   ".into(),
             heartbeat_interval_ms: 1,
             peer_public_key: None,
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
         let err = empty_id.validate().unwrap_err();
         assert!(matches!(err, CoreError::InvalidConfig { .. }));
@@ -185,6 +752,15 @@ mod tests {
             client_id: "id".into(),
             heartbeat_interval_ms: 0,
             peer_public_key: None,
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
         let err = zero_heartbeat.validate().unwrap_err();
         assert!(matches!(err, CoreError::InvalidConfig { .. }));
@@ -193,8 +769,183 @@ mod tests {
             client_id: "id".into(),
             heartbeat_interval_ms: 10,
             peer_public_key: Some(Vec::new()),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         };
         let err = empty_key.validate().unwrap_err();
         assert!(matches!(err, CoreError::InvalidConfig { .. }));
     }
+
+    #[test]
+    fn breaker_trips_after_threshold_and_recovers() {
+        let registry = BreakerRegistry::new();
+        for _ in 0..CIRCUIT_FAILURE_THRESHOLD {
+            registry.should_try("device-a").unwrap();
+            registry.record_failure("device-a");
+        }
+
+        let err = registry.should_try("device-a").unwrap_err();
+        assert!(matches!(err, CoreError::CircuitOpen { .. }));
+
+        // Other keys are unaffected.
+        registry.should_try("device-b").unwrap();
+    }
+
+    #[test]
+    fn breaker_closes_on_success() {
+        let registry = BreakerRegistry::new();
+        registry.should_try("device-c").unwrap();
+        registry.record_failure("device-c");
+        registry.record_success("device-c");
+        registry.should_try("device-c").unwrap();
+    }
+
+    #[test]
+    fn reconnect_strategy_caps_at_max_delay() {
+        let strategy = ReconnectStrategy {
+            initial_delay: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(1),
+            max_attempts: Some(3),
+        };
+        assert_eq!(strategy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(strategy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(strategy.delay_for(10), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn reconnect_strategy_jitter_never_exceeds_cap() {
+        let strategy = ReconnectStrategy::default();
+        for attempt in 0..5 {
+            let jittered = strategy.jittered_delay_for(attempt);
+            assert!(jittered <= strategy.delay_for(attempt));
+        }
+    }
+
+    #[test]
+    fn reconnect_strategy_respects_max_attempts() {
+        let strategy = ReconnectStrategy {
+            max_attempts: Some(3),
+            ..ReconnectStrategy::default()
+        };
+        assert!(!strategy.attempts_exhausted(2));
+        assert!(strategy.attempts_exhausted(3));
+        assert!(!ReconnectStrategy {
+            max_attempts: None,
+            ..strategy
+        }
+        .attempts_exhausted(1_000));
+    }
+
+    #[test]
+    fn liveness_tolerance_window_scales_with_heartbeat_interval() {
+        let config = LivenessConfig {
+            missed_interval_tolerance: 3,
+            ..LivenessConfig::default()
+        };
+        assert_eq!(config.tolerance_window(1_000), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn liveness_grace_jitter_never_exceeds_cap() {
+        let config = LivenessConfig::default();
+        for _ in 0..5 {
+            assert!(config.jittered_grace() <= config.max_grace_jitter);
+        }
+    }
+
+    #[test]
+    fn rtt_estimator_tracks_a_stable_signal() {
+        let mut estimator = RttEstimator::from_sample(100.0);
+        for _ in 0..20 {
+            estimator.update(100.0);
+        }
+        assert!((estimator.srtt_ms() - 100.0).abs() < 0.01);
+
+        // srtt + 4*rttvar is about 101 ms, below the default 1_000 ms floor.
+        let config = AdaptiveLivenessConfig::default();
+        assert_eq!(estimator.deadline_ms(&config), 1_000);
+    }
+
+    #[test]
+    fn rtt_estimator_deadline_clamps_to_configured_bounds() {
+        let config = AdaptiveLivenessConfig {
+            min_deadline_ms: 500,
+            max_deadline_ms: 2_000,
+            fallback_multiplier: 3,
+        };
+
+        let tight = RttEstimator::from_sample(10.0);
+        assert_eq!(tight.deadline_ms(&config), 500);
+
+        let noisy = RttEstimator::from_sample(5_000.0);
+        assert_eq!(noisy.deadline_ms(&config), 2_000);
+    }
+
+    #[test]
+    fn event_broker_coalesces_non_critical_events_under_overflow() {
+        let broker = EventBroker::new(4);
+        broker.push(SessionEvent::Connected);
+        broker.push(SessionEvent::InputReceived(vec![1]));
+        broker.push(SessionEvent::InputReceived(vec![2]));
+        broker.push(SessionEvent::InputReceived(vec![3]));
+
+        // Buffer is full; the oldest InputReceived should be coalesced away
+        // to make room, never the Connected milestone.
+        broker.push(SessionEvent::InputReceived(vec![4]));
+
+        let mut drained = Vec::new();
+        while let Some(event) = broker.poll() {
+            drained.push(event);
+        }
+
+        assert!(drained.contains(&SessionEvent::Connected));
+        assert!(!drained.contains(&SessionEvent::InputReceived(vec![1])));
+        assert!(drained.iter().any(|event| matches!(
+            event,
+            SessionEvent::EventsDropped { count } if *count >= 1
+        )));
+        assert!(broker.dropped_events() >= 1);
+    }
+
+    #[test]
+    fn event_broker_never_drops_lifecycle_events() {
+        let broker = EventBroker::new(3);
+        broker.push(SessionEvent::InputReceived(vec![1]));
+        broker.push(SessionEvent::InputReceived(vec![2]));
+        broker.push(SessionEvent::Connected);
+
+        // The buffer is saturated; further pushes should only ever coalesce
+        // away the non-critical backlog, never the lifecycle milestones.
+        broker.push(SessionEvent::HeartbeatTimeout);
+        broker.push(SessionEvent::ReconnectExhausted);
+
+        let mut drained = Vec::new();
+        while let Some(event) = broker.poll() {
+            drained.push(event);
+        }
+
+        assert!(drained.contains(&SessionEvent::Connected));
+        assert!(drained.contains(&SessionEvent::HeartbeatTimeout));
+        assert!(drained.contains(&SessionEvent::ReconnectExhausted));
+    }
+
+    #[tokio::test]
+    async fn event_broker_next_event_awaits_a_push() {
+        let broker = Arc::new(EventBroker::new(4));
+        let waiter = Arc::clone(&broker);
+        let task = tokio::spawn(async move { waiter.next_event().await });
+
+        tokio::task::yield_now().await;
+        broker.push(SessionEvent::Disconnected);
+
+        assert_eq!(task.await.unwrap(), Some(SessionEvent::Disconnected));
+    }
 }
@@ -1,4 +1,7 @@
 use crate::error::CoreError;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
 
 /// Represents bitrate control requests.
 #[derive(Debug, Clone, Copy)]
@@ -27,3 +30,195 @@ pub trait FileTransferCoordinator {
     async fn upload(&self, path: &str) -> Result<(), CoreError>;
     async fn download(&self, remote_path: &str) -> Result<(), CoreError>;
 }
+
+/// Configuration for the AIMD adaptive bitrate loop.
+#[derive(Debug, Clone, Copy)]
+pub struct AbrConfig {
+    /// How often the controller should be sampled/stepped.
+    pub interval: Duration,
+    /// Packet loss ratio (0.0-1.0) above which the target is decreased.
+    pub congestion_loss_threshold: f32,
+    /// Additive increase applied each non-congested interval.
+    pub increase_step_bps: u64,
+    /// Multiplicative decrease applied on congestion (e.g. 0.85 == -15%).
+    pub decrease_factor: f32,
+    /// Minimum target bitrate, preserving a usable stream under congestion.
+    pub floor_bps: u64,
+    /// Maximum target bitrate the controller will ever request.
+    pub ceiling_bps: u64,
+    /// Number of recent RTT samples used to detect an upward trend.
+    pub rtt_trend_window: usize,
+}
+
+impl Default for AbrConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            congestion_loss_threshold: 0.02,
+            increase_step_bps: 100_000,
+            decrease_factor: 0.85,
+            floor_bps: 250_000,
+            ceiling_bps: 8_000_000,
+            rtt_trend_window: 4,
+        }
+    }
+}
+
+/// A source of RTT samples the bitrate controller reacts to.
+///
+/// Kept independent of `StreamMetrics` so callers with their own round-trip
+/// measurement (e.g. a heartbeat) can feed it in without the stream module
+/// needing to know how RTT is measured.
+pub trait RttSampler {
+    fn sample_rtt(&self) -> Duration;
+}
+
+/// An `RttSampler` that always reports zero latency; a harmless default
+/// until a real RTT source is wired in.
+pub struct NullRttSampler;
+
+impl RttSampler for NullRttSampler {
+    fn sample_rtt(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// Loss- and latency-driven AIMD controller that closes the loop between
+/// `StreamController::metrics` and `adjust_flow`.
+#[derive(Debug)]
+pub struct AdaptiveBitrateController {
+    config: AbrConfig,
+    current_bps: Mutex<u64>,
+    rtt_history: Mutex<VecDeque<Duration>>,
+}
+
+impl AdaptiveBitrateController {
+    pub fn new(config: AbrConfig, initial_target_bps: u64) -> Self {
+        let clamped = initial_target_bps.clamp(config.floor_bps, config.ceiling_bps);
+        Self {
+            config,
+            current_bps: Mutex::new(clamped),
+            rtt_history: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn current_target(&self) -> u64 {
+        *self.current_bps.lock().unwrap()
+    }
+
+    /// Whether the last `rtt_trend_window` RTT samples are strictly increasing,
+    /// used as a delay-gradient guard that suppresses increases before loss
+    /// actually appears.
+    fn rtt_trending_up(&self) -> bool {
+        let history = self.rtt_history.lock().unwrap();
+        history.len() >= 2 && history.iter().zip(history.iter().skip(1)).all(|(a, b)| b > a)
+    }
+
+    /// Runs one AIMD step given the latest metrics and RTT samples, updates
+    /// the internal target, and returns the `FlowRate` to apply.
+    pub fn step(&self, metrics: StreamMetrics, rtt: Duration, max_latency_ms: u32) -> FlowRate {
+        {
+            let mut history = self.rtt_history.lock().unwrap();
+            history.push_back(rtt);
+            while history.len() > self.config.rtt_trend_window {
+                history.pop_front();
+            }
+        }
+
+        let congested = metrics.packet_loss > self.config.congestion_loss_threshold
+            || rtt.as_millis() as u32 > max_latency_ms;
+        let trending_up = self.rtt_trending_up();
+
+        let mut current = self.current_bps.lock().unwrap();
+        if congested {
+            *current = (*current as f32 * self.config.decrease_factor) as u64;
+        } else if !trending_up {
+            *current = current.saturating_add(self.config.increase_step_bps);
+        }
+        *current = (*current).clamp(self.config.floor_bps, self.config.ceiling_bps);
+
+        FlowRate {
+            target_bitrate_bps: *current,
+            max_latency_ms,
+        }
+    }
+
+    /// Samples `controller`/`rtt_sampler` and pushes the recomputed target
+    /// through `adjust_flow`, returning the new `FlowRate`.
+    pub async fn sample_and_adjust<C, R>(
+        &self,
+        controller: &C,
+        rtt_sampler: &R,
+        max_latency_ms: u32,
+    ) -> FlowRate
+    where
+        C: StreamController,
+        R: RttSampler,
+    {
+        let metrics = controller.metrics().await;
+        let rtt = rtt_sampler.sample_rtt();
+        let rate = self.step(metrics, rtt, max_latency_ms);
+        controller.adjust_flow(rate).await;
+        rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(loss: f32) -> StreamMetrics {
+        StreamMetrics {
+            bitrate_bps: 0,
+            packet_loss: loss,
+        }
+    }
+
+    #[test]
+    fn increases_additively_when_healthy() {
+        let controller = AdaptiveBitrateController::new(AbrConfig::default(), 1_000_000);
+        let rate = controller.step(metrics(0.0), Duration::from_millis(20), 100);
+        assert_eq!(rate.target_bitrate_bps, 1_100_000);
+    }
+
+    #[test]
+    fn decreases_multiplicatively_on_loss() {
+        let controller = AdaptiveBitrateController::new(AbrConfig::default(), 1_000_000);
+        let rate = controller.step(metrics(0.05), Duration::from_millis(20), 100);
+        assert_eq!(rate.target_bitrate_bps, 850_000);
+    }
+
+    #[test]
+    fn decreases_on_excess_latency_even_without_loss() {
+        let controller = AdaptiveBitrateController::new(AbrConfig::default(), 1_000_000);
+        let rate = controller.step(metrics(0.0), Duration::from_millis(200), 100);
+        assert_eq!(rate.target_bitrate_bps, 850_000);
+    }
+
+    #[test]
+    fn respects_floor_and_ceiling() {
+        let config = AbrConfig {
+            floor_bps: 500_000,
+            ceiling_bps: 1_050_000,
+            ..AbrConfig::default()
+        };
+        let controller = AdaptiveBitrateController::new(config, 1_000_000);
+        let rate = controller.step(metrics(0.0), Duration::from_millis(1), 100);
+        assert_eq!(rate.target_bitrate_bps, 1_050_000);
+
+        let controller = AdaptiveBitrateController::new(config, 550_000);
+        let rate = controller.step(metrics(0.5), Duration::from_millis(1), 100);
+        assert_eq!(rate.target_bitrate_bps, 500_000);
+    }
+
+    #[test]
+    fn rising_rtt_trend_suppresses_increase_before_loss_appears() {
+        let controller = AdaptiveBitrateController::new(AbrConfig::default(), 1_000_000);
+        controller.step(metrics(0.0), Duration::from_millis(10), 100);
+        controller.step(metrics(0.0), Duration::from_millis(20), 100);
+        let rate = controller.step(metrics(0.0), Duration::from_millis(30), 100);
+        // The first step holds a single sample, so it still adds one step;
+        // the two rising samples after it add nothing.
+        assert_eq!(rate.target_bitrate_bps, 1_100_000);
+    }
+}
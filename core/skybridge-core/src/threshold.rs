@@ -0,0 +1,406 @@
+use crate::crypto::{
+    AeadSuiteId, KeyExchangeProvider, KeyMaterial, SessionCryptoProvider, SessionSecrets,
+};
+use crate::error::{CoreError, CoreResult};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::elliptic_curve::Field;
+use p256::{AffinePoint, ProjectivePoint, PublicKey, Scalar};
+use rand_core::OsRng;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// `SessionCryptoProvider::algorithm()` identifier for `ThresholdSessionCrypto`,
+/// checked by `CoreEngine::initialize` against `SessionConfig::threshold_params`
+/// so a caller can't silently run single-peer secrets while believing it
+/// configured threshold custody.
+pub const THRESHOLD_ALGORITHM_ID: &str = "P-256-threshold";
+
+/// Parameters negotiated for threshold (M-of-N) distributed key generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThresholdParams {
+    /// Minimum number of shares required to reconstruct the secret.
+    pub threshold: u16,
+    /// Total number of custodian servers the secret is split across.
+    pub total_shares: u16,
+}
+
+/// A single custodian's Shamir share `f(index)` of the secret polynomial.
+#[derive(Debug, Clone, Copy)]
+pub struct Share {
+    pub index: u16,
+    pub value: Scalar,
+}
+
+/// Feldman VSS commitments `g^{a_j}` to each coefficient of the sharing
+/// polynomial, letting any holder verify `g^{f(i)} == Π (g^{a_j})^{i^j}`
+/// without learning the secret.
+#[derive(Debug, Clone)]
+pub struct FeldmanCommitments(Vec<ProjectivePoint>);
+
+/// Builds a `Scalar` equal to the small non-negative integer `index`, using
+/// only field addition/doubling so it works without a `From<u64>` impl.
+fn scalar_from_index(index: u16) -> Scalar {
+    let mut acc = Scalar::ZERO;
+    let mut bit = Scalar::ONE;
+    let mut remaining = index;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            acc += bit;
+        }
+        bit += bit;
+        remaining >>= 1;
+    }
+    acc
+}
+
+fn evaluate_polynomial(coefficients: &[Scalar], x: Scalar) -> Scalar {
+    let mut acc = Scalar::ZERO;
+    let mut power = Scalar::ONE;
+    for coefficient in coefficients {
+        acc += *coefficient * power;
+        power *= x;
+    }
+    acc
+}
+
+fn evaluate_commitment(commitments: &[ProjectivePoint], x: Scalar) -> ProjectivePoint {
+    let mut acc = ProjectivePoint::IDENTITY;
+    let mut power = Scalar::ONE;
+    for commitment in commitments {
+        acc += *commitment * power;
+        power *= x;
+    }
+    acc
+}
+
+/// Splits `secret` into `total` Feldman-verifiable Shamir shares such that
+/// any `threshold` of them reconstruct it, via a random degree-`(threshold-1)`
+/// polynomial `f` with `f(0) = secret`.
+pub fn split_secret(
+    secret: Scalar,
+    threshold: usize,
+    total: usize,
+) -> CoreResult<(Vec<Share>, FeldmanCommitments)> {
+    if threshold == 0 || threshold > total {
+        return Err(CoreError::InvalidConfig {
+            reason: "threshold must be between 1 and the total share count".into(),
+        });
+    }
+
+    let mut coefficients = Vec::with_capacity(threshold);
+    coefficients.push(secret);
+    for _ in 1..threshold {
+        coefficients.push(Scalar::random(&mut OsRng));
+    }
+
+    let commitments = coefficients
+        .iter()
+        .map(|coefficient| ProjectivePoint::GENERATOR * coefficient)
+        .collect();
+
+    let shares = (1..=total as u16)
+        .map(|index| Share {
+            index,
+            value: evaluate_polynomial(&coefficients, scalar_from_index(index)),
+        })
+        .collect();
+
+    Ok((shares, FeldmanCommitments(commitments)))
+}
+
+/// Verifies `share` against the dealer's published `commitments` without
+/// reconstructing or otherwise learning the shared secret.
+pub fn verify_share(commitments: &FeldmanCommitments, share: &Share) -> bool {
+    let expected = evaluate_commitment(&commitments.0, scalar_from_index(share.index));
+    let actual = ProjectivePoint::GENERATOR * share.value;
+    expected == actual
+}
+
+/// Reconstructs the secret at `x = 0` via Lagrange interpolation over the
+/// first `threshold` of `shares`. This is the only place the full secret
+/// may legitimately come back together — individual custodians never see it.
+pub fn reconstruct_secret(shares: &[Share], threshold: usize) -> CoreResult<Scalar> {
+    if shares.len() < threshold {
+        return Err(CoreError::InsufficientShares {
+            required: threshold,
+            received: shares.len(),
+        });
+    }
+
+    let used = &shares[..threshold];
+    let mut seen_indices = HashSet::with_capacity(used.len());
+    for share in used {
+        if !seen_indices.insert(share.index) {
+            return Err(CoreError::ShareVerificationFailed { index: share.index });
+        }
+    }
+
+    let mut secret = Scalar::ZERO;
+    for (k, share_i) in used.iter().enumerate() {
+        let xi = scalar_from_index(share_i.index);
+        let mut lambda = Scalar::ONE;
+        for (l, share_j) in used.iter().enumerate() {
+            if k == l {
+                continue;
+            }
+            let xj = scalar_from_index(share_j.index);
+            let denominator = xi - xj;
+            let denom_inv: Option<Scalar> = denominator.invert().into();
+            let denom_inv = denom_inv.ok_or(CoreError::ShareVerificationFailed {
+                index: share_j.index,
+            })?;
+            lambda *= (-xj) * denom_inv;
+        }
+        secret += share_i.value * lambda;
+    }
+
+    Ok(secret)
+}
+
+/// Computes the ECDH shared secret for `scalar` against `peer_public_key`,
+/// taking only the X-coordinate of the resulting point — the same shape
+/// `KeyMaterial::derive` returns via `raw_secret_bytes()` for the crate's
+/// other ECDH path (`P256KeyExchange`), so a threshold-mode peer derives an
+/// identical shared secret to a standard P-256 peer computing the same ECDH,
+/// and downstream HKDF derivation in `SessionSecrets::new` sees the input
+/// shape it expects from every other caller.
+fn derive_shared_from_scalar(scalar: Scalar, peer_public_key: &[u8]) -> CoreResult<Vec<u8>> {
+    let peer = PublicKey::from_sec1_bytes(peer_public_key).map_err(|_| CoreError::InvalidCryptoKey)?;
+    let shared_point = ProjectivePoint::from(peer.as_affine().to_owned()) * scalar;
+    let affine: AffinePoint = shared_point.into();
+    let encoded = affine.to_encoded_point(false);
+    let x = encoded.x().ok_or(CoreError::InvalidCryptoKey)?;
+    Ok(x.to_vec())
+}
+
+/// Abstracts the network round trip to the M-of-N custodian servers holding
+/// shares of the engine's ephemeral secret. A real implementation fans out
+/// to each custodian and collects the share it returns for `session_id`;
+/// the in-memory `LocalCustodianPool` below exists for tests and single-box
+/// deployments where the "custodians" are just separate in-process shares.
+#[async_trait::async_trait(?Send)]
+pub trait CustodianPool {
+    async fn fetch_shares(&self, session_id: &str) -> CoreResult<Vec<Share>>;
+}
+
+/// A `CustodianPool` that hands back shares it was dealt in-process, useful
+/// for tests and for deployments that co-locate the custodians with the
+/// engine (e.g. threshold custody purely against process compromise).
+pub struct LocalCustodianPool {
+    shares: Mutex<Vec<Share>>,
+}
+
+impl LocalCustodianPool {
+    pub fn new(shares: Vec<Share>) -> Self {
+        Self {
+            shares: Mutex::new(shares),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl CustodianPool for LocalCustodianPool {
+    async fn fetch_shares(&self, _session_id: &str) -> CoreResult<Vec<Share>> {
+        Ok(self.shares.lock().unwrap().clone())
+    }
+}
+
+/// Session crypto backed by threshold (M-of-N) distributed key generation:
+/// the ephemeral secret scalar is Shamir-shared across custodian servers via
+/// Feldman VSS, and `finalize_handshake` reconstructs it only after at least
+/// `threshold` verified shares are gathered back from `custodians`. No
+/// single custodian holds the full secret; the engine does, since
+/// `begin_handshake` generates a full key pair through `exchange` and keeps
+/// it in `local_key`.
+pub struct ThresholdSessionCrypto<E: KeyExchangeProvider + Send + Sync, C: CustodianPool> {
+    exchange: E,
+    custodians: C,
+    threshold: usize,
+    commitments: Mutex<Option<FeldmanCommitments>>,
+    local_key: Mutex<Option<KeyMaterial>>,
+}
+
+impl<E, C> ThresholdSessionCrypto<E, C>
+where
+    E: KeyExchangeProvider + Send + Sync,
+    C: CustodianPool,
+{
+    pub fn new(exchange: E, custodians: C, threshold: usize) -> Self {
+        Self {
+            exchange,
+            custodians,
+            threshold,
+            commitments: Mutex::new(None),
+            local_key: Mutex::new(None),
+        }
+    }
+
+    /// Publishes the Feldman commitments the dealer produced when splitting
+    /// the secret, so shares gathered in `finalize_handshake` can be
+    /// verified before being combined.
+    pub fn set_commitments(&self, commitments: FeldmanCommitments) {
+        *self.commitments.lock().unwrap() = Some(commitments);
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[async_trait::async_trait(?Send)]
+impl<E, C> SessionCryptoProvider for ThresholdSessionCrypto<E, C>
+where
+    E: KeyExchangeProvider + Send + Sync,
+    C: CustodianPool,
+{
+    async fn validate_device_identity(&self) -> CoreResult<()> {
+        Ok(())
+    }
+
+    async fn begin_handshake(&self) -> CoreResult<Vec<u8>> {
+        let material = self.exchange.generate().await?;
+        let public_key = material.public_key.clone();
+        *self.local_key.lock().unwrap() = Some(material);
+        Ok(public_key)
+    }
+
+    async fn finalize_handshake(&self, peer_public_key: &[u8]) -> CoreResult<SessionSecrets> {
+        let local = {
+            let mut guard = self.local_key.lock().unwrap();
+            guard.take().ok_or(CoreError::MissingCryptoMaterial)?
+        };
+        let session_id = hex_encode(&local.public_key);
+        *self.local_key.lock().unwrap() = Some(local);
+
+        let shares = self.custodians.fetch_shares(&session_id).await?;
+        if shares.len() < self.threshold {
+            return Err(CoreError::InsufficientShares {
+                required: self.threshold,
+                received: shares.len(),
+            });
+        }
+
+        if let Some(commitments) = self.commitments.lock().unwrap().as_ref() {
+            for share in &shares {
+                if !verify_share(commitments, share) {
+                    return Err(CoreError::ShareVerificationFailed { index: share.index });
+                }
+            }
+        }
+
+        let reconstructed = reconstruct_secret(&shares, self.threshold)?;
+        let shared = derive_shared_from_scalar(reconstructed, peer_public_key)?;
+        let local_public_key = self.local_public_key().unwrap_or_default();
+        SessionSecrets::new(shared, AeadSuiteId::Aes256Gcm, &local_public_key, peer_public_key)
+    }
+
+    fn local_public_key(&self) -> Option<Vec<u8>> {
+        self.local_key
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|material| material.public_key.clone())
+    }
+
+    fn algorithm(&self) -> &'static str {
+        THRESHOLD_ALGORITHM_ID
+    }
+
+    fn encrypt(&self, secrets: &SessionSecrets, plaintext: &[u8]) -> CoreResult<Vec<u8>> {
+        self.exchange_encrypt(secrets, plaintext)
+    }
+
+    fn decrypt(&self, secrets: &SessionSecrets, ciphertext: &[u8]) -> CoreResult<Vec<u8>> {
+        self.exchange_decrypt(secrets, ciphertext)
+    }
+}
+
+impl<E, C> ThresholdSessionCrypto<E, C>
+where
+    E: KeyExchangeProvider + Send + Sync,
+    C: CustodianPool,
+{
+    fn exchange_encrypt(&self, secrets: &SessionSecrets, plaintext: &[u8]) -> CoreResult<Vec<u8>> {
+        crate::crypto::aead_encrypt(secrets, plaintext)
+    }
+
+    fn exchange_decrypt(&self, secrets: &SessionSecrets, ciphertext: &[u8]) -> CoreResult<Vec<u8>> {
+        crate::crypto::aead_decrypt(secrets, ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_reconstruct_the_original_secret() {
+        let secret = Scalar::random(&mut OsRng);
+        let (shares, _commitments) = split_secret(secret, 3, 5).unwrap();
+
+        let reconstructed = reconstruct_secret(&shares[0..3], 3).unwrap();
+        assert_eq!(reconstructed, secret);
+
+        let reconstructed_other_subset = reconstruct_secret(&shares[1..4], 3).unwrap();
+        assert_eq!(reconstructed_other_subset, secret);
+    }
+
+    #[test]
+    fn feldman_commitments_verify_honest_shares() {
+        let secret = Scalar::random(&mut OsRng);
+        let (shares, commitments) = split_secret(secret, 3, 5).unwrap();
+        for share in &shares {
+            assert!(verify_share(&commitments, share));
+        }
+    }
+
+    #[test]
+    fn tampered_share_fails_verification() {
+        let secret = Scalar::random(&mut OsRng);
+        let (mut shares, commitments) = split_secret(secret, 3, 5).unwrap();
+        shares[0].value += Scalar::ONE;
+        assert!(!verify_share(&commitments, &shares[0]));
+    }
+
+    #[test]
+    fn reconstruction_rejects_insufficient_shares() {
+        let secret = Scalar::random(&mut OsRng);
+        let (shares, _commitments) = split_secret(secret, 3, 5).unwrap();
+
+        let err = reconstruct_secret(&shares[0..2], 3).unwrap_err();
+        assert!(matches!(err, CoreError::InsufficientShares { .. }));
+    }
+
+    #[tokio::test]
+    async fn threshold_handshake_reconstructs_and_matches_peer() {
+        use crate::crypto::P256KeyExchange;
+
+        let local_material = P256KeyExchange.generate().await.unwrap();
+        let remote_material = P256KeyExchange.generate().await.unwrap();
+
+        // Dealer splits the local ephemeral scalar across 5 custodians,
+        // requiring any 3 to finalize the handshake.
+        let local_scalar = *local_material.secret_scalar();
+        let (shares, commitments) = split_secret(local_scalar, 3, 5).unwrap();
+        let local_public_key = local_material.public_key.clone();
+
+        let crypto = ThresholdSessionCrypto::new(
+            P256KeyExchange,
+            LocalCustodianPool::new(shares[0..3].to_vec()),
+            3,
+        );
+        crypto.set_commitments(commitments);
+
+        *crypto.local_key.lock().unwrap() = Some(local_material);
+        let secrets = crypto
+            .finalize_handshake(&remote_material.public_key)
+            .await
+            .unwrap();
+        assert!(!secrets.shared_secret.is_empty());
+
+        // The remote peer runs the same ECDH from its side of the exchange.
+        let peer_shared =
+            derive_shared_from_scalar(*remote_material.secret_scalar(), &local_public_key).unwrap();
+        assert_eq!(secrets.shared_secret, peer_shared);
+    }
+}
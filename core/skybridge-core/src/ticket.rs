@@ -0,0 +1,335 @@
+use crate::crypto::{AeadSuiteId, SessionSecrets};
+use crate::error::{CoreError, CoreResult};
+use crate::session::SessionConfig;
+use aes_gcm::{aead::Aead, Aes256Gcm, KeyInit};
+use hkdf::Hkdf;
+use rand_core::{OsRng, RngCore};
+use sha2::Sha256;
+use std::sync::Mutex;
+
+#[allow(deprecated)]
+type AeadNonce = aes_gcm::aead::generic_array::GenericArray<u8, aes_gcm::aead::consts::U12>;
+
+/// Width of the sliding window of accepted ticket counters; bounds how far a
+/// redeemed ticket's counter may jump ahead of the last one consumed.
+const TICKET_WINDOW: u64 = 64;
+
+/// An opaque, authenticated resumption ticket sealing negotiated session
+/// state so a future `CoreEngine::resume` can skip the Diffie-Hellman round
+/// trips of a full handshake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResumptionTicket(Vec<u8>);
+
+impl ResumptionTicket {
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Issues and redeems [`ResumptionTicket`]s under a per-engine device key,
+/// rejecting tickets whose counter has already been consumed or falls
+/// outside the sliding acceptance window.
+#[derive(Debug)]
+pub struct TicketAuthority {
+    device_key: [u8; 32],
+    issue_counter: Mutex<u64>,
+    highest_consumed: Mutex<Option<u64>>,
+}
+
+impl TicketAuthority {
+    pub fn new() -> Self {
+        let mut device_key = [0u8; 32];
+        OsRng.fill_bytes(&mut device_key);
+        Self {
+            device_key,
+            issue_counter: Mutex::new(0),
+            highest_consumed: Mutex::new(None),
+        }
+    }
+
+    fn cipher(&self) -> CoreResult<Aes256Gcm> {
+        Aes256Gcm::new_from_slice(&self.device_key)
+            .map_err(|e| CoreError::Crypto(format!("ticket key init failed: {e}")))
+    }
+
+    /// Seals `secrets` and `config` into an opaque ticket tagged with the
+    /// next monotonically increasing issue counter.
+    ///
+    /// Also mints a fresh 32-byte resumption nonce and bakes it, plus this
+    /// session's current ratchet send direction, into the ticket. Without
+    /// the nonce, `redeem` would feed `secrets.shared_secret` into
+    /// `SessionSecrets::new` completely unchanged, reproducing the exact
+    /// generation-0 key and nonce the original session already used to seal
+    /// traffic before the ticket was issued — catastrophic AEAD key+nonce
+    /// reuse. Without the saved direction, `redeem` would have no live peer
+    /// key left to compare and would always assign the same role.
+    pub fn issue(&self, secrets: &SessionSecrets, config: &SessionConfig) -> CoreResult<ResumptionTicket> {
+        let counter = {
+            let mut guard = self.issue_counter.lock().unwrap();
+            *guard += 1;
+            *guard
+        };
+
+        let mut resume_nonce = [0u8; 32];
+        OsRng.fill_bytes(&mut resume_nonce);
+
+        let peer_key = config.peer_public_key.clone().unwrap_or_default();
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&counter.to_le_bytes());
+        payload.extend_from_slice(&(config.client_id.len() as u16).to_le_bytes());
+        payload.extend_from_slice(config.client_id.as_bytes());
+        payload.extend_from_slice(&config.heartbeat_interval_ms.to_le_bytes());
+        payload.extend_from_slice(&(peer_key.len() as u16).to_le_bytes());
+        payload.extend_from_slice(&peer_key);
+        payload.extend_from_slice(&(secrets.shared_secret.len() as u16).to_le_bytes());
+        payload.extend_from_slice(&secrets.shared_secret);
+        payload.extend_from_slice(&resume_nonce);
+        payload.push(secrets.ratchet_send_direction());
+
+        let cipher = self.cipher()?;
+        let mut nonce_bytes = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce: AeadNonce = nonce_bytes.into();
+        let mut sealed = cipher
+            .encrypt(&nonce, payload.as_slice())
+            .map_err(|e| CoreError::Encrypt(format!("ticket seal failed: {e}")))?;
+
+        let mut ticket = nonce.to_vec();
+        ticket.append(&mut sealed);
+        Ok(ResumptionTicket(ticket))
+    }
+
+    /// Validates and opens `ticket`, returning the restored `SessionConfig`
+    /// and `SessionSecrets`. Rejects tickets whose counter has already been
+    /// consumed or that jump further ahead than `TICKET_WINDOW`.
+    pub fn redeem(&self, ticket: &ResumptionTicket) -> CoreResult<(SessionConfig, SessionSecrets)> {
+        let bytes = &ticket.0;
+        if bytes.len() < 12 {
+            return Err(CoreError::ResumptionRejected {
+                reason: "ticket too short".into(),
+            });
+        }
+        let (nonce_bytes, body) = bytes.split_at(12);
+        let cipher = self.cipher()?;
+        let nonce_array: [u8; 12] = nonce_bytes.try_into().map_err(|_| CoreError::ResumptionRejected {
+            reason: "malformed nonce".into(),
+        })?;
+        let nonce: AeadNonce = nonce_array.into();
+        let payload = cipher
+            .decrypt(&nonce, body)
+            .map_err(|_| CoreError::ResumptionRejected {
+                reason: "ticket authentication failed".into(),
+            })?;
+
+        let (counter, config, shared_secret, resume_nonce, send_direction) =
+            parse_ticket_payload(&payload)?;
+
+        self.check_and_record_counter(counter)?;
+
+        // Mix the ticket's fresh resumption nonce into the original shared
+        // secret so this generation's keys and ratchet nonces can never
+        // collide with the original session's (see `issue`'s doc comment),
+        // and restore the original session's send direction rather than
+        // recomputing it from a peer-key comparison that has nothing left
+        // to compare against.
+        let hk = Hkdf::<Sha256>::new(None, &shared_secret);
+        let mut resumed_secret = vec![0u8; 32];
+        hk.expand(&resume_nonce, &mut resumed_secret)
+            .map_err(|e| CoreError::CryptoHandshake(format!("hkdf expand failed: {e}")))?;
+
+        Ok((
+            config,
+            SessionSecrets::new_resumed(resumed_secret, AeadSuiteId::Aes256Gcm, send_direction)?,
+        ))
+    }
+
+    fn check_and_record_counter(&self, counter: u64) -> CoreResult<()> {
+        let mut highest = self.highest_consumed.lock().unwrap();
+        if let Some(previous) = *highest {
+            if counter <= previous {
+                return Err(CoreError::ResumptionRejected {
+                    reason: "ticket counter already consumed".into(),
+                });
+            }
+            if counter > previous.saturating_add(TICKET_WINDOW) {
+                return Err(CoreError::ResumptionRejected {
+                    reason: "ticket counter outside acceptance window".into(),
+                });
+            }
+        }
+        *highest = Some(counter);
+        Ok(())
+    }
+}
+
+impl Default for TicketAuthority {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Unpacks a decrypted ticket payload into its counter, restored
+/// `SessionConfig`, raw shared secret, fresh resumption nonce, and original
+/// ratchet send direction.
+#[allow(clippy::type_complexity)]
+fn parse_ticket_payload(payload: &[u8]) -> CoreResult<(u64, SessionConfig, Vec<u8>, [u8; 32], u8)> {
+    let malformed = || CoreError::ResumptionRejected {
+        reason: "malformed ticket payload".into(),
+    };
+
+    let mut cursor = 0usize;
+    let read = |cursor: &mut usize, len: usize| -> CoreResult<std::ops::Range<usize>> {
+        let end = cursor.checked_add(len).ok_or_else(malformed)?;
+        if end > payload.len() {
+            return Err(malformed());
+        }
+        let range = *cursor..end;
+        *cursor = end;
+        Ok(range)
+    };
+
+    let counter = u64::from_le_bytes(payload[read(&mut cursor, 8)?].try_into().unwrap());
+
+    let client_id_len = u16::from_le_bytes(payload[read(&mut cursor, 2)?].try_into().unwrap()) as usize;
+    let client_id = String::from_utf8(payload[read(&mut cursor, client_id_len)?].to_vec())
+        .map_err(|_| malformed())?;
+
+    let heartbeat_interval_ms = u64::from_le_bytes(payload[read(&mut cursor, 8)?].try_into().unwrap());
+
+    let peer_key_len = u16::from_le_bytes(payload[read(&mut cursor, 2)?].try_into().unwrap()) as usize;
+    let peer_key = payload[read(&mut cursor, peer_key_len)?].to_vec();
+
+    let secret_len = u16::from_le_bytes(payload[read(&mut cursor, 2)?].try_into().unwrap()) as usize;
+    let shared_secret = payload[read(&mut cursor, secret_len)?].to_vec();
+
+    let resume_nonce: [u8; 32] = payload[read(&mut cursor, 32)?]
+        .try_into()
+        .map_err(|_| malformed())?;
+
+    let send_direction = payload[read(&mut cursor, 1)?][0];
+
+    if cursor != payload.len() {
+        return Err(malformed());
+    }
+
+    let config = SessionConfig {
+        client_id,
+        heartbeat_interval_ms,
+        peer_public_key: if peer_key.is_empty() { None } else { Some(peer_key) },
+        abr_config: None,
+        crypto_pool: None,
+        reconnect_strategy: None,
+        protocol_version: 1,
+        min_supported: 1,
+        adaptive_liveness: None,
+        threshold_params: None,
+        peer_identity: None,
+        peer_suite_preference: None,
+    };
+
+    Ok((counter, config, shared_secret, resume_nonce, send_direction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_config() -> SessionConfig {
+        SessionConfig {
+            client_id: "device-42".into(),
+            heartbeat_interval_ms: 1_000,
+            peer_public_key: Some(vec![1, 2, 3, 4]),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
+        }
+    }
+
+    fn sample_secrets() -> SessionSecrets {
+        SessionSecrets::new(vec![9u8; 32], AeadSuiteId::Aes256Gcm, &[], &[]).unwrap()
+    }
+
+    #[test]
+    fn ticket_round_trips_config_and_secrets() {
+        let authority = TicketAuthority::new();
+        let original = sample_secrets();
+        let ticket = authority.issue(&original, &sample_config()).unwrap();
+
+        let (config, secrets) = authority.redeem(&ticket).unwrap();
+        assert_eq!(config.client_id, "device-42");
+        assert_eq!(config.peer_public_key, Some(vec![1, 2, 3, 4]));
+        // The resumed secret must never equal the original verbatim: it's
+        // mixed with a fresh per-ticket resumption nonce so the resumed
+        // session's keys and ratchet nonces can't collide with whatever the
+        // original session already sent under `original`'s generation-0 key.
+        assert_ne!(secrets.shared_secret, original.shared_secret);
+    }
+
+    #[test]
+    fn two_tickets_from_the_same_secrets_resume_to_different_keys() {
+        let authority = TicketAuthority::new();
+        let secrets = sample_secrets();
+        let first = authority.issue(&secrets, &sample_config()).unwrap();
+        let second = authority.issue(&secrets, &sample_config()).unwrap();
+
+        let (_, resumed_first) = authority.redeem(&first).unwrap();
+        let (_, resumed_second) = authority.redeem(&second).unwrap();
+        assert_ne!(resumed_first.shared_secret, resumed_second.shared_secret);
+    }
+
+    #[test]
+    fn redeemed_ticket_preserves_original_send_direction() {
+        let authority = TicketAuthority::new();
+        let secrets = sample_secrets();
+        let ticket = authority.issue(&secrets, &sample_config()).unwrap();
+
+        let (_, resumed) = authority.redeem(&ticket).unwrap();
+        assert_eq!(
+            resumed.ratchet_send_direction(),
+            secrets.ratchet_send_direction()
+        );
+    }
+
+    #[test]
+    fn replayed_ticket_counter_is_rejected() {
+        let authority = TicketAuthority::new();
+        let ticket = authority.issue(&sample_secrets(), &sample_config()).unwrap();
+
+        authority.redeem(&ticket).unwrap();
+        let err = authority.redeem(&ticket).unwrap_err();
+        assert!(matches!(err, CoreError::ResumptionRejected { .. }));
+    }
+
+    #[test]
+    fn ticket_far_outside_window_is_rejected() {
+        let authority = TicketAuthority::new();
+        let first = authority.issue(&sample_secrets(), &sample_config()).unwrap();
+        let mut far_ahead = None;
+        for _ in 0..TICKET_WINDOW + 5 {
+            far_ahead = Some(authority.issue(&sample_secrets(), &sample_config()).unwrap());
+        }
+
+        authority.redeem(&first).unwrap();
+        let err = authority.redeem(&far_ahead.unwrap()).unwrap_err();
+        assert!(matches!(err, CoreError::ResumptionRejected { .. }));
+    }
+
+    #[test]
+    fn forged_ticket_is_rejected() {
+        let authority = TicketAuthority::new();
+        let err = authority
+            .redeem(&ResumptionTicket::from_bytes(vec![0u8; 40]))
+            .unwrap_err();
+        assert!(matches!(err, CoreError::ResumptionRejected { .. }));
+    }
+}
@@ -0,0 +1,3 @@
+//! Transport implementations that back the session/stream traits.
+
+pub mod quic;
@@ -0,0 +1,296 @@
+//! QUIC-backed transport that keeps a session alive across network changes.
+//!
+//! Modeled loosely on neqo's connection-migration design: a datagram from a
+//! new `SocketAddr` starts validation of a candidate *path* rather than a
+//! session teardown, so a NAT rebinding or a Wi-Fi → cellular handoff keeps
+//! the session. A new path is only trusted once it completes a
+//! PATH_CHALLENGE / PATH_RESPONSE round trip. Paths are tracked by
+//! `SocketAddr`; each side holds a single connection ID, drawn anew by
+//! `establish_async` and not rotated otherwise.
+
+use crate::error::{CoreError, CoreResult};
+use crate::session::{AsyncSessionManager, SessionConfig, SessionState};
+use crate::stream::{FlowRate, StreamController, StreamMetrics};
+use rand_core::{OsRng, RngCore};
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A rotating connection identifier, decoupled from the 4-tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionId(pub [u8; 8]);
+
+impl ConnectionId {
+    fn random() -> Self {
+        let mut bytes = [0u8; 8];
+        OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+}
+
+/// An 8-byte PATH_CHALLENGE/PATH_RESPONSE token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathToken(pub [u8; 8]);
+
+impl PathToken {
+    fn random() -> Self {
+        let mut bytes = [0u8; 8];
+        OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+}
+
+/// A migration lifecycle event that `CoreEngine` can record for diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathMigrationEvent {
+    /// A datagram arrived from a new address; validation has started.
+    ValidationStarted(SocketAddr),
+    /// The candidate path answered PATH_CHALLENGE correctly and is now active.
+    Migrated(SocketAddr),
+    /// The candidate path never returned a valid PATH_RESPONSE in time.
+    ValidationFailed(SocketAddr),
+}
+
+#[derive(Debug, Clone)]
+struct CandidatePath {
+    addr: SocketAddr,
+    challenge: PathToken,
+    sent_at: Instant,
+}
+
+#[derive(Debug)]
+struct QuicState {
+    active_addr: Option<SocketAddr>,
+    fallback_addr: Option<SocketAddr>,
+    local_cid: ConnectionId,
+    peer_cid: Option<ConnectionId>,
+    candidate: Option<CandidatePath>,
+    events: Vec<PathMigrationEvent>,
+    smoothed_rtt: Duration,
+}
+
+impl QuicState {
+    fn new() -> Self {
+        Self {
+            active_addr: None,
+            fallback_addr: None,
+            local_cid: ConnectionId::random(),
+            peer_cid: None,
+            candidate: None,
+            events: Vec::new(),
+            smoothed_rtt: Duration::from_millis(100),
+        }
+    }
+}
+
+/// How long we wait for a PATH_RESPONSE, scaled off the smoothed RTT.
+fn path_validation_timeout(rtt: Duration) -> Duration {
+    rtt.mul_f32(3.0).max(Duration::from_millis(50))
+}
+
+/// QUIC-backed session manager and stream controller sharing one connection.
+///
+/// `AsyncSessionManager::establish_async`/`reconnect_async` bring the
+/// connection up; `migrate_to` is the entry point a transport-level receive
+/// loop calls whenever a datagram shows up from an unexpected `SocketAddr`.
+#[derive(Debug)]
+pub struct QuicTransport {
+    state: Mutex<QuicState>,
+}
+
+impl QuicTransport {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(QuicState::new()),
+        }
+    }
+
+    /// Returns the single connection ID this endpoint advertises; it only
+    /// changes when `establish_async` draws a new one.
+    pub fn local_connection_id(&self) -> ConnectionId {
+        self.state.lock().unwrap().local_cid
+    }
+
+    /// Records the connection ID the peer advertised for itself.
+    pub fn set_peer_connection_id(&self, cid: ConnectionId) {
+        self.state.lock().unwrap().peer_cid = Some(cid);
+    }
+
+    /// The address currently trusted for sending datagrams, if any.
+    pub fn active_path(&self) -> Option<SocketAddr> {
+        self.state.lock().unwrap().active_addr
+    }
+
+    /// Drains recorded migration events for diagnostics/telemetry.
+    pub fn drain_events(&self) -> Vec<PathMigrationEvent> {
+        std::mem::take(&mut self.state.lock().unwrap().events)
+    }
+
+    /// Called when a datagram arrives from `from`. Begins path validation if
+    /// `from` differs from the currently active address.
+    ///
+    /// Returns the PATH_CHALLENGE token the caller must send to `from`.
+    pub fn on_datagram_from(&self, from: SocketAddr) -> Option<PathToken> {
+        let mut guard = self.state.lock().unwrap();
+        if guard.active_addr == Some(from) {
+            return None;
+        }
+        let challenge = PathToken::random();
+        guard.fallback_addr = guard.active_addr;
+        guard.candidate = Some(CandidatePath {
+            addr: from,
+            challenge,
+            sent_at: Instant::now(),
+        });
+        guard.events.push(PathMigrationEvent::ValidationStarted(from));
+        Some(challenge)
+    }
+
+    /// Feeds a PATH_RESPONSE back to the state machine. Only migrates if the
+    /// echoed token matches the outstanding challenge and arrives within the
+    /// RTT-scaled timeout; otherwise the old path remains active.
+    pub fn on_path_response(&self, from: SocketAddr, token: PathToken) -> CoreResult<()> {
+        let mut guard = self.state.lock().unwrap();
+        let timeout = path_validation_timeout(guard.smoothed_rtt);
+        let candidate = match &guard.candidate {
+            Some(c) if c.addr == from => c.clone(),
+            _ => return Err(CoreError::PathValidationFailed),
+        };
+
+        if candidate.challenge != token || candidate.sent_at.elapsed() > timeout {
+            guard.candidate = None;
+            guard.events.push(PathMigrationEvent::ValidationFailed(from));
+            return Err(CoreError::PathValidationFailed);
+        }
+
+        guard.active_addr = Some(from);
+        guard.candidate = None;
+        // A confirmed path change resets the RTT estimate for the new path;
+        // `QuicTransport` keeps no congestion state of its own to reset.
+        guard.smoothed_rtt = Duration::from_millis(100);
+        guard.events.push(PathMigrationEvent::Migrated(from));
+        Ok(())
+    }
+
+    /// Explicit timeout check for an outstanding candidate path, useful when
+    /// no PATH_RESPONSE ever arrives and nothing else would observe the gap.
+    pub fn expire_candidate_if_stale(&self) {
+        let mut guard = self.state.lock().unwrap();
+        let timeout = path_validation_timeout(guard.smoothed_rtt);
+        if let Some(candidate) = &guard.candidate {
+            if candidate.sent_at.elapsed() > timeout {
+                let addr = candidate.addr;
+                guard.candidate = None;
+                guard.events.push(PathMigrationEvent::ValidationFailed(addr));
+            }
+        }
+    }
+}
+
+impl Default for QuicTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl AsyncSessionManager for QuicTransport {
+    async fn establish_async(&self, config: SessionConfig) -> CoreResult<()> {
+        if config.client_id.trim().is_empty() {
+            return Err(CoreError::Session("missing client id".into()));
+        }
+        let mut guard = self.state.lock().unwrap();
+        guard.local_cid = ConnectionId::random();
+        Ok(())
+    }
+
+    async fn reconnect_async(&self) -> CoreResult<()> {
+        // Migration keeps the old path as a fallback; reconnect just confirms
+        // whichever path is currently active is still usable.
+        let guard = self.state.lock().unwrap();
+        if guard.active_addr.is_none() && guard.fallback_addr.is_none() {
+            return Err(CoreError::Session("no known path to reconnect over".into()));
+        }
+        Ok(())
+    }
+
+    async fn terminate_async(&self) {
+        let mut guard = self.state.lock().unwrap();
+        guard.active_addr = None;
+        guard.fallback_addr = None;
+        guard.candidate = None;
+    }
+
+    fn state(&self) -> SessionState {
+        if self.state.lock().unwrap().active_addr.is_some() {
+            SessionState::Connected
+        } else {
+            SessionState::Disconnected
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl StreamController for QuicTransport {
+    async fn adjust_flow(&self, _rate: FlowRate) {
+        // The rate is ignored: `QuicTransport` keeps no congestion state, and
+        // path migration resets only its RTT estimate.
+    }
+
+    async fn metrics(&self) -> StreamMetrics {
+        StreamMetrics {
+            bitrate_bps: 0,
+            packet_loss: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migration_requires_matching_token() {
+        let transport = QuicTransport::new();
+        let initial: SocketAddr = "127.0.0.1:1111".parse().unwrap();
+        let roaming: SocketAddr = "127.0.0.1:2222".parse().unwrap();
+
+        transport.state.lock().unwrap().active_addr = Some(initial);
+
+        let challenge = transport.on_datagram_from(roaming).expect("new path seen");
+        let err = transport
+            .on_path_response(roaming, PathToken([0u8; 8]))
+            .expect_err("wrong token must not migrate");
+        assert!(matches!(err, CoreError::PathValidationFailed));
+        assert_eq!(transport.active_path(), Some(initial));
+
+        // Re-arm with the real challenge (the previous failure cleared it).
+        let challenge2 = transport.on_datagram_from(roaming).unwrap_or(challenge);
+        transport
+            .on_path_response(roaming, challenge2)
+            .expect("matching token migrates");
+        assert_eq!(transport.active_path(), Some(roaming));
+    }
+
+    #[test]
+    fn same_address_does_not_trigger_validation() {
+        let transport = QuicTransport::new();
+        let addr: SocketAddr = "127.0.0.1:1111".parse().unwrap();
+        transport.state.lock().unwrap().active_addr = Some(addr);
+        assert!(transport.on_datagram_from(addr).is_none());
+    }
+
+    #[test]
+    fn expired_candidate_is_reported_as_failed() {
+        let transport = QuicTransport::new();
+        let roaming: SocketAddr = "127.0.0.1:3333".parse().unwrap();
+        transport.state.lock().unwrap().smoothed_rtt = Duration::from_millis(0);
+        transport.on_datagram_from(roaming);
+        std::thread::sleep(Duration::from_millis(60));
+        transport.expire_candidate_if_stale();
+        let events = transport.drain_events();
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, PathMigrationEvent::ValidationFailed(_))));
+    }
+}
@@ -1,14 +1,18 @@
 use skybridge_core::ffi::{
-    skybridge_engine_check_liveness, skybridge_engine_clear_events, skybridge_engine_connect,
+    skybridge_engine_check_liveness, skybridge_engine_check_liveness_auto,
+    skybridge_engine_clear_events, skybridge_engine_connect,
     skybridge_engine_decrypt_payload, skybridge_engine_disconnect,
     skybridge_engine_encrypt_payload, skybridge_engine_free, skybridge_engine_last_input_len,
-    skybridge_engine_local_public_key, skybridge_engine_metrics, skybridge_engine_new,
-    skybridge_engine_poll_events, skybridge_engine_reconnect, skybridge_engine_send_heartbeat,
-    skybridge_engine_send_input, skybridge_engine_snapshot, skybridge_engine_state,
-    skybridge_engine_throttle_stream, SkybridgeBuffer, SkybridgeEngineSnapshot, SkybridgeErrorCode,
-    SkybridgeEvent, SkybridgeEventKind, SkybridgeFlowRate, SkybridgeSessionConfig,
-    SkybridgeSessionState, SkybridgeStreamMetrics, SKYBRIDGE_EVENT_CAPACITY,
+    skybridge_engine_local_public_key, skybridge_engine_metrics,
+    skybridge_engine_negotiated_version, skybridge_engine_new, skybridge_engine_poll_events,
+    skybridge_engine_reconnect, skybridge_engine_report_stream_sample,
+    skybridge_engine_send_heartbeat, skybridge_engine_send_input, skybridge_engine_snapshot,
+    skybridge_engine_state, skybridge_engine_throttle_stream, SkybridgeBuffer,
+    SkybridgeEngineSnapshot, SkybridgeErrorCode, SkybridgeEvent, SkybridgeEventKind,
+    SkybridgeFlowRate, SkybridgeSessionConfig, SkybridgeSessionState, SkybridgeStreamMetrics,
+    SKYBRIDGE_EVENT_CAPACITY,
 };
+use skybridge_core::PROTOCOL_VERSION_MAX;
 use std::os::raw::c_char;
 use std::ptr;
 
@@ -35,6 +39,14 @@ fn ffi_engine_lifecycle_runs() {
         heartbeat_interval_ms: 10,
         peer_public_key_ptr: local_key.as_ptr(),
         peer_public_key_len: local_key.len(),
+        reconnect_base_delay_ms: 0,
+        reconnect_max_delay_ms: 0,
+        reconnect_max_retries: 0,
+        protocol_version: 1,
+        min_supported: 1,
+        adaptive_liveness_min_deadline_ms: 0,
+        adaptive_liveness_max_deadline_ms: 0,
+        adaptive_liveness_fallback_multiplier: 0,
     };
 
     let connect_result = skybridge_engine_connect(handle, config);
@@ -47,12 +59,25 @@ fn ffi_engine_lifecycle_runs() {
         last_heartbeat_ms: 0,
         has_last_heartbeat: false,
         has_secrets: false,
+        negotiated_version: 0,
+        dropped_events: 0,
+        srtt_ms: 0,
+        has_srtt: false,
+        liveness_deadline_ms: 0,
+        has_liveness_deadline: false,
     };
     let snapshot_res = unsafe { skybridge_engine_snapshot(handle, &mut snapshot) };
     assert_eq!(snapshot_res, SkybridgeErrorCode::Ok);
     assert_eq!(snapshot.state, SkybridgeSessionState::Connected);
     assert!(snapshot.has_secrets);
     assert!(!snapshot.has_last_heartbeat);
+    assert_eq!(snapshot.negotiated_version, 1);
+    assert_eq!(snapshot.dropped_events, 0);
+    assert!(!snapshot.has_srtt);
+    // No ack has landed yet; the deadline falls back to
+    // heartbeat_interval_ms * AdaptiveLivenessConfig::default().fallback_multiplier.
+    assert!(snapshot.has_liveness_deadline);
+    assert_eq!(snapshot.liveness_deadline_ms, 30);
 
     let mut event = SkybridgeEvent {
         kind: SkybridgeEventKind::None,
@@ -71,9 +96,12 @@ fn ffi_engine_lifecycle_runs() {
     let snapshot_res = unsafe { skybridge_engine_snapshot(handle, &mut snapshot) };
     assert_eq!(snapshot_res, SkybridgeErrorCode::Ok);
     assert!(snapshot.has_last_heartbeat);
+    assert!(snapshot.has_srtt);
 
     let liveness_ok = skybridge_engine_check_liveness(handle, 2);
     assert_eq!(liveness_ok, SkybridgeErrorCode::Ok);
+    let auto_liveness_ok = skybridge_engine_check_liveness_auto(handle);
+    assert_eq!(auto_liveness_ok, SkybridgeErrorCode::Ok);
 
     std::thread::sleep(std::time::Duration::from_millis(30));
     let timeout_res = skybridge_engine_check_liveness(handle, 2);
@@ -185,6 +213,14 @@ fn ffi_connect_rejects_invalid_config() {
         heartbeat_interval_ms: 0,
         peer_public_key_ptr: local_key.as_ptr(),
         peer_public_key_len: local_key.len(),
+        reconnect_base_delay_ms: 0,
+        reconnect_max_delay_ms: 0,
+        reconnect_max_retries: 0,
+        protocol_version: 1,
+        min_supported: 1,
+        adaptive_liveness_min_deadline_ms: 0,
+        adaptive_liveness_max_deadline_ms: 0,
+        adaptive_liveness_fallback_multiplier: 0,
     };
 
     let connect_result = skybridge_engine_connect(handle, config);
@@ -217,6 +253,14 @@ fn ffi_event_queue_is_bounded_and_clearable() {
         heartbeat_interval_ms: 5,
         peer_public_key_ptr: local_key.as_ptr(),
         peer_public_key_len: local_key.len(),
+        reconnect_base_delay_ms: 0,
+        reconnect_max_delay_ms: 0,
+        reconnect_max_retries: 0,
+        protocol_version: 1,
+        min_supported: 1,
+        adaptive_liveness_min_deadline_ms: 0,
+        adaptive_liveness_max_deadline_ms: 0,
+        adaptive_liveness_fallback_multiplier: 0,
     };
     assert_eq!(
         skybridge_engine_connect(handle, config),
@@ -232,6 +276,7 @@ fn ffi_event_queue_is_bounded_and_clearable() {
     }
 
     let mut polled = 0usize;
+    let mut saw_dropped_marker = false;
     let mut event = SkybridgeEvent {
         kind: SkybridgeEventKind::None,
         data_ptr: ptr::null(),
@@ -243,10 +288,31 @@ fn ffi_event_queue_is_bounded_and_clearable() {
         if event.kind == SkybridgeEventKind::None {
             break;
         }
+        if event.kind == SkybridgeEventKind::EventsDropped {
+            saw_dropped_marker = true;
+        }
         polled += 1;
     }
 
     assert!(polled <= SKYBRIDGE_EVENT_CAPACITY);
+    // Overflow is signalled explicitly rather than silently dropped.
+    assert!(saw_dropped_marker);
+
+    let mut snapshot = SkybridgeEngineSnapshot {
+        state: SkybridgeSessionState::Disconnected,
+        last_heartbeat_ms: 0,
+        has_last_heartbeat: false,
+        has_secrets: false,
+        negotiated_version: 0,
+        dropped_events: 0,
+        srtt_ms: 0,
+        has_srtt: false,
+        liveness_deadline_ms: 0,
+        has_liveness_deadline: false,
+    };
+    let snapshot_res = unsafe { skybridge_engine_snapshot(handle, &mut snapshot) };
+    assert_eq!(snapshot_res, SkybridgeErrorCode::Ok);
+    assert!(snapshot.dropped_events > 0);
 
     // Clearing should drop any leftover events and payload references.
     assert_eq!(
@@ -260,3 +326,153 @@ fn ffi_event_queue_is_bounded_and_clearable() {
 
     unsafe { skybridge_engine_free(handle) };
 }
+
+#[test]
+fn ffi_connect_negotiates_down_to_a_common_version() {
+    let handle = skybridge_engine_new();
+    assert!(!handle.is_null());
+
+    let mut local_public = SkybridgeBuffer {
+        data_ptr: ptr::null(),
+        data_len: 0,
+    };
+    unsafe { skybridge_engine_local_public_key(handle, &mut local_public) };
+    let local_key =
+        unsafe { std::slice::from_raw_parts(local_public.data_ptr, local_public.data_len) };
+
+    let client_id = b"version-downgrade";
+    let config = SkybridgeSessionConfig {
+        client_id_ptr: client_id.as_ptr() as *const c_char,
+        client_id_len: client_id.len(),
+        heartbeat_interval_ms: 10,
+        peer_public_key_ptr: local_key.as_ptr(),
+        peer_public_key_len: local_key.len(),
+        reconnect_base_delay_ms: 0,
+        reconnect_max_delay_ms: 0,
+        reconnect_max_retries: 0,
+        protocol_version: PROTOCOL_VERSION_MAX + 1,
+        min_supported: 1,
+        adaptive_liveness_min_deadline_ms: 0,
+        adaptive_liveness_max_deadline_ms: 0,
+        adaptive_liveness_fallback_multiplier: 0,
+    };
+
+    let connect_result = skybridge_engine_connect(handle, config);
+    assert_eq!(connect_result, SkybridgeErrorCode::Ok);
+    assert_eq!(skybridge_engine_negotiated_version(handle), PROTOCOL_VERSION_MAX);
+
+    unsafe { skybridge_engine_free(handle) };
+}
+
+#[test]
+fn ffi_connect_rejects_incompatible_min_supported_version() {
+    let handle = skybridge_engine_new();
+    assert!(!handle.is_null());
+
+    let mut local_public = SkybridgeBuffer {
+        data_ptr: ptr::null(),
+        data_len: 0,
+    };
+    unsafe { skybridge_engine_local_public_key(handle, &mut local_public) };
+    let local_key =
+        unsafe { std::slice::from_raw_parts(local_public.data_ptr, local_public.data_len) };
+
+    let client_id = b"version-incompatible";
+    let config = SkybridgeSessionConfig {
+        client_id_ptr: client_id.as_ptr() as *const c_char,
+        client_id_len: client_id.len(),
+        heartbeat_interval_ms: 10,
+        peer_public_key_ptr: local_key.as_ptr(),
+        peer_public_key_len: local_key.len(),
+        reconnect_base_delay_ms: 0,
+        reconnect_max_delay_ms: 0,
+        reconnect_max_retries: 0,
+        protocol_version: PROTOCOL_VERSION_MAX + 1,
+        min_supported: PROTOCOL_VERSION_MAX + 1,
+        adaptive_liveness_min_deadline_ms: 0,
+        adaptive_liveness_max_deadline_ms: 0,
+        adaptive_liveness_fallback_multiplier: 0,
+    };
+
+    let connect_result = skybridge_engine_connect(handle, config);
+    assert_eq!(connect_result, SkybridgeErrorCode::UnsupportedProtocol);
+    assert_eq!(
+        skybridge_engine_state(handle),
+        SkybridgeSessionState::Disconnected
+    );
+    assert_eq!(skybridge_engine_negotiated_version(handle), 0);
+
+    unsafe { skybridge_engine_free(handle) };
+}
+
+#[test]
+fn ffi_report_stream_sample_drives_bitrate_down_under_congestion() {
+    let handle = skybridge_engine_new();
+    assert!(!handle.is_null());
+
+    let mut local_public = SkybridgeBuffer {
+        data_ptr: ptr::null(),
+        data_len: 0,
+    };
+    unsafe { skybridge_engine_local_public_key(handle, &mut local_public) };
+    let local_key =
+        unsafe { std::slice::from_raw_parts(local_public.data_ptr, local_public.data_len) };
+
+    let client_id = b"congestion-feedback";
+    let config = SkybridgeSessionConfig {
+        client_id_ptr: client_id.as_ptr() as *const c_char,
+        client_id_len: client_id.len(),
+        heartbeat_interval_ms: 10,
+        peer_public_key_ptr: local_key.as_ptr(),
+        peer_public_key_len: local_key.len(),
+        reconnect_base_delay_ms: 0,
+        reconnect_max_delay_ms: 0,
+        reconnect_max_retries: 0,
+        protocol_version: 1,
+        min_supported: 1,
+        adaptive_liveness_min_deadline_ms: 0,
+        adaptive_liveness_max_deadline_ms: 0,
+        adaptive_liveness_fallback_multiplier: 0,
+    };
+    assert_eq!(
+        skybridge_engine_connect(handle, config),
+        SkybridgeErrorCode::Ok
+    );
+
+    let mut event = SkybridgeEvent {
+        kind: SkybridgeEventKind::None,
+        data_ptr: ptr::null(),
+        data_len: 0,
+    };
+    let connect_event = unsafe { skybridge_engine_poll_events(handle, &mut event) };
+    assert_eq!(connect_event, SkybridgeErrorCode::Ok);
+    assert_eq!(event.kind, SkybridgeEventKind::Connected);
+
+    let flow = SkybridgeFlowRate {
+        target_bitrate_bps: 1_000_000,
+        max_latency_ms: 100,
+    };
+    assert_eq!(
+        skybridge_engine_throttle_stream(handle, flow),
+        SkybridgeErrorCode::Ok
+    );
+
+    // High loss should drive the AIMD target below the throttled ceiling.
+    let sample_result = skybridge_engine_report_stream_sample(handle, 50_000, 20);
+    assert_eq!(sample_result, SkybridgeErrorCode::Ok);
+
+    let mut metrics = SkybridgeStreamMetrics {
+        bitrate_bps: 0,
+        packet_loss_ppm: 0,
+    };
+    let metrics_result = unsafe { skybridge_engine_metrics(handle, &mut metrics) };
+    assert_eq!(metrics_result, SkybridgeErrorCode::Ok);
+    assert!(metrics.bitrate_bps < flow.target_bitrate_bps);
+
+    let bitrate_event = unsafe { skybridge_engine_poll_events(handle, &mut event) };
+    assert_eq!(bitrate_event, SkybridgeErrorCode::Ok);
+    assert_eq!(event.kind, SkybridgeEventKind::BitrateChanged);
+    assert_eq!(event.data_len, 8);
+
+    unsafe { skybridge_engine_free(handle) };
+}
@@ -165,6 +165,15 @@ async fn state_machine_transitions_and_metrics() {
         client_id: "integration".into(),
         heartbeat_interval_ms: 25,
         peer_public_key: Some(sample_peer_key().await),
+        abr_config: None,
+        crypto_pool: None,
+        reconnect_strategy: None,
+        protocol_version: 1,
+        min_supported: 1,
+        adaptive_liveness: None,
+        threshold_params: None,
+        peer_identity: None,
+        peer_suite_preference: None,
     };
 
     engine.initialize(config).await.unwrap();
@@ -224,6 +233,15 @@ async fn integration_heartbeat_throttle_is_enforced() {
             client_id: "integration".into(),
             heartbeat_interval_ms: 100,
             peer_public_key: Some(sample_peer_key().await),
+            abr_config: None,
+            crypto_pool: None,
+            reconnect_strategy: None,
+            protocol_version: 1,
+            min_supported: 1,
+            adaptive_liveness: None,
+            threshold_params: None,
+            peer_identity: None,
+            peer_suite_preference: None,
         })
         .await
         .unwrap();